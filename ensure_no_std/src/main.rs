@@ -13,9 +13,9 @@ fn panic(_info: &core::panic::PanicInfo) -> ! {
     exit_no_std::exit(99)
 }
 
-use dos_cp::CodePage;
+use dos_cp::{CodePage, include_code_page};
 
-include!(concat!(env!("OUT_DIR"), "/cp852.rs"));
+const CP852: CodePage = include_code_page!(852);
 
 #[start]
 pub fn main(_argc: isize, _argv: *const *const u8) -> isize {
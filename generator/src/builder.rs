@@ -0,0 +1,249 @@
+use crate::{DbcsError, KNOWN_CODE_PAGES, Mapping, NoParamError, dbcs_lead_ranges, find_hash_param, generate_dbcs};
+use crate::mapping::duplicate_mapping;
+use dos_cp::{CODE_PAGE_ARCHIVE_ENTRY_SIZE, CODE_PAGE_ARCHIVE_HEADER_SIZE, CODE_PAGE_FILE_HEADER_SIZE};
+use dos_cp::{CodePage, CodePageKind, Inconsistency, archive_entry, archive_header, file_header, tables};
+use std::env::var_os;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter, Write as _};
+use std::fs::{File, create_dir_all};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum BuildError {
+    UnknownCodePage { code_page: u16 },
+    NoOutDir,
+    NoParam { code_page: u16, error: NoParamError },
+    Inconsistent { code_page: u16, inconsistency: Inconsistency },
+    Dbcs { code_page: u16, error: DbcsError },
+    TooManyCodePages { count: usize },
+    ArchiveTooBig,
+    Io(io::Error),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            BuildError::UnknownCodePage { code_page } => write!(f, "unknown code page {code_page}"),
+            BuildError::NoOutDir => write!(f, "no output directory specified and OUT_DIR is not set"),
+            BuildError::NoParam { code_page, error } => write!(f, "code page {code_page}: {error}"),
+            BuildError::Inconsistent { code_page, inconsistency } =>
+                write!(f, "code page {code_page}: inconsistent table: {inconsistency}"),
+            BuildError::Dbcs { code_page, error } => write!(f, "code page {code_page}: {error}"),
+            BuildError::TooManyCodePages { count } => write!(f, "too many code pages for an archive ({count})"),
+            BuildError::ArchiveTooBig => write!(f, "archive exceeds 4 GiB"),
+            BuildError::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BuildError::NoParam { error, .. } => Some(error),
+            BuildError::Dbcs { error, .. } => Some(error),
+            BuildError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BuildError {
+    fn from(error: io::Error) -> BuildError {
+        BuildError::Io(error)
+    }
+}
+
+struct Table<'a> {
+    code_page: u16,
+    upper_half: &'a [char; 128],
+    hash_param: u16,
+    low_half_overrides: &'a [(u8, char)],
+}
+
+impl<'a> Table<'a> {
+    fn code_page_table(&self) -> Result<CodePage, BuildError> {
+        if let Some(inconsistency) = duplicate_mapping(self.upper_half) {
+            return Err(BuildError::Inconsistent { code_page: self.code_page, inconsistency });
+        }
+        let code_page = CodePage::with_hash_param(self.upper_half, self.hash_param)
+            .with_low_half_overrides(self.low_half_overrides);
+        if let Some(inconsistency) = code_page.verify().next() {
+            return Err(BuildError::Inconsistent { code_page: self.code_page, inconsistency });
+        }
+        Ok(code_page)
+    }
+
+    fn write_rust_source(&self, source: &mut String) {
+        writeln!(source, "pub static CP{}: dos_cp::CodePage = dos_cp::CodePage::with_hash_param(&[", self.code_page).unwrap();
+        for chars in self.upper_half.chunks(8) {
+            source.push_str("   ");
+            for &c in chars {
+                if c == '?' {
+                    source.push_str(" '?',");
+                } else {
+                    write!(source, " '\\u{{{:04X}}}',", c as u32).unwrap();
+                }
+            }
+            source.push('\n');
+        }
+        write!(source, "], 0x{:04X}).with_low_half_overrides(&[", self.hash_param).unwrap();
+        for (i, &(b, c)) in self.low_half_overrides.iter().enumerate() {
+            if i != 0 { source.push_str(", "); }
+            write!(source, "(0x{b:02X}, '\\u{{{:04X}}}')", c as u32).unwrap();
+        }
+        source.push_str("]);\n\n");
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Builder {
+    pages: Option<Vec<u16>>,
+    custom_tables: Vec<(u16, Mapping)>,
+    dbcs_tables: Vec<(u16, Vec<(u16, char)>)>,
+    out_dir: Option<PathBuf>,
+    archive: Option<PathBuf>,
+    rust_source: Option<PathBuf>,
+}
+
+impl Builder {
+    pub fn new() -> Builder {
+        Builder::default()
+    }
+
+    /// Known code pages to build, all of them by default.
+    pub fn pages(mut self, pages: impl IntoIterator<Item=u16>) -> Builder {
+        self.pages = Some(pages.into_iter().collect());
+        self
+    }
+
+    /// Adds a table or replaces a known one.
+    pub fn custom_table(mut self, code_page: u16, mapping: Mapping) -> Builder {
+        self.custom_tables.retain(|&(n, _)| n != code_page);
+        self.dbcs_tables.retain(|&(n, _)| n != code_page);
+        self.custom_tables.push((code_page, mapping));
+        self
+    }
+
+    /// Adds a double-byte table, the code page has to be one of those with known [`dbcs_lead_ranges`].
+    pub fn dbcs_table(mut self, code_page: u16, mapping: impl IntoIterator<Item=(u16, char)>) -> Builder {
+        self.custom_tables.retain(|&(n, _)| n != code_page);
+        self.dbcs_tables.retain(|&(n, _)| n != code_page);
+        self.dbcs_tables.push((code_page, mapping.into_iter().collect()));
+        self
+    }
+
+    /// Defaults to `$OUT_DIR/CODEPAGE`.
+    pub fn out_dir(mut self, out_dir: impl Into<PathBuf>) -> Builder {
+        self.out_dir = Some(out_dir.into());
+        self
+    }
+
+    /// Writes a single indexed archive (`CODEPAGE.DAT`) instead of a directory of separate files.
+    pub fn archive(mut self, path: impl Into<PathBuf>) -> Builder {
+        self.archive = Some(path.into());
+        self
+    }
+
+    /// Also writes the selected single-byte tables as Rust source defining `CPNNN` statics
+    /// and a `code_page(n: u16) -> Option<&'static CodePage>` lookup function.
+    /// Double-byte tables are not included.
+    pub fn rust_source(mut self, path: impl Into<PathBuf>) -> Builder {
+        self.rust_source = Some(path.into());
+        self
+    }
+
+    fn tables(&self) -> Result<Vec<Table<'_>>, BuildError> {
+        let mut res = Vec::new();
+        for &code_page in self.pages.as_deref().unwrap_or(KNOWN_CODE_PAGES) {
+            if self.custom_tables.iter().any(|&(n, _)| n == code_page) { continue; }
+            let (upper_half, hash_param) = tables::upper_half_and_hash_param(code_page)
+                .ok_or(BuildError::UnknownCodePage { code_page })?;
+            let hash_param = match hash_param {
+                Some(hash_param) => hash_param,
+                None => find_hash_param(upper_half).map_err(|error| BuildError::NoParam { code_page, error })?,
+            };
+            let low_half_overrides = tables::low_half_overrides(code_page);
+            res.push(Table { code_page, upper_half, hash_param, low_half_overrides });
+        }
+        for (code_page, mapping) in &self.custom_tables {
+            let code_page = *code_page;
+            let hash_param = find_hash_param(&mapping.upper_half)
+                .map_err(|error| BuildError::NoParam { code_page, error })?;
+            res.push(Table {
+                code_page,
+                upper_half: &mapping.upper_half,
+                hash_param,
+                low_half_overrides: &mapping.low_half_overrides,
+            });
+        }
+        Ok(res)
+    }
+
+    fn dbcs_code_page_tables(&self) -> Result<Vec<(u16, Vec<u8>)>, BuildError> {
+        let mut res = Vec::new();
+        for (code_page, mapping) in &self.dbcs_tables {
+            let code_page = *code_page;
+            let lead_ranges = dbcs_lead_ranges(code_page).ok_or(BuildError::UnknownCodePage { code_page })?;
+            let code_page_table = generate_dbcs(lead_ranges, mapping.iter().copied())
+                .map_err(|error| BuildError::Dbcs { code_page, error })?;
+            res.push((code_page, code_page_table));
+        }
+        Ok(res)
+    }
+
+    pub fn build(self) -> Result<(), BuildError> {
+        let tables = self.tables()?;
+        let code_page_tables = tables.iter().map(Table::code_page_table).collect::<Result<Vec<_>, _>>()?;
+        let dbcs_code_page_tables = self.dbcs_code_page_tables()?;
+        let files = tables.iter().zip(&code_page_tables)
+            .map(|(table, code_page_table)| (table.code_page, CodePageKind::SingleByte, code_page_table.as_bytes()))
+            .chain(dbcs_code_page_tables.iter().map(|(code_page, body)| (*code_page, CodePageKind::DoubleByte, &body[..])))
+            .collect::<Vec<_>>();
+        if let Some(archive) = &self.archive {
+            let count = u16::try_from(files.len()).map_err(|_| BuildError::TooManyCodePages { count: files.len() })?;
+            let mut offset = CODE_PAGE_ARCHIVE_HEADER_SIZE + files.len() * CODE_PAGE_ARCHIVE_ENTRY_SIZE;
+            let mut index = archive_header(count).to_vec();
+            let mut data = Vec::new();
+            for &(code_page, kind, body) in &files {
+                let entry_offset = offset.try_into().map_err(|_| BuildError::ArchiveTooBig)?;
+                index.extend_from_slice(&archive_entry(code_page, entry_offset));
+                data.extend_from_slice(&file_header(code_page, kind, body));
+                data.extend_from_slice(body);
+                offset += CODE_PAGE_FILE_HEADER_SIZE + body.len();
+            }
+            if let Some(dir) = archive.parent() {
+                create_dir_all(dir)?;
+            }
+            let mut file = File::create(archive)?;
+            file.write_all(&index)?;
+            file.write_all(&data)?;
+        } else {
+            let out_dir = match &self.out_dir {
+                Some(out_dir) => out_dir.clone(),
+                None => PathBuf::from(var_os("OUT_DIR").ok_or(BuildError::NoOutDir)?).join("CODEPAGE"),
+            };
+            create_dir_all(&out_dir)?;
+            for &(code_page, kind, body) in &files {
+                let mut file = File::create(out_dir.join(format!("{}", code_page)))?;
+                file.write_all(&file_header(code_page, kind, body))?;
+                file.write_all(body)?;
+            }
+        }
+        if let Some(rust_source) = &self.rust_source {
+            let mut source = String::new();
+            for table in &tables {
+                table.write_rust_source(&mut source);
+            }
+            source.push_str("#[allow(clippy::match_single_binding)]\n");
+            source.push_str("pub fn code_page(code_page: u16) -> Option<&'static dos_cp::CodePage> {\n");
+            source.push_str("    match code_page {\n");
+            for table in &tables {
+                writeln!(source, "        {} => Some(&CP{}),", table.code_page, table.code_page).unwrap();
+            }
+            source.push_str("        _ => None,\n    }\n}\n");
+            File::create(rust_source)?.write_all(source.as_bytes())?;
+        }
+        Ok(())
+    }
+}
@@ -1,31 +1,149 @@
 #![deny(warnings)]
 
-use dos_cp::{CodePage, hash};
-use std::env::var_os;
-use std::fs::{File, create_dir_all};
-use std::io::Write;
-use std::mem::{MaybeUninit, transmute};
+use dos_cp::{CodePage, DBCS_HEADER_SIZE, DBCS_REVERSE_PAGE_SIZE, DBCS_ROW_SIZE, DbcsCodePage};
+use dos_cp::tables;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs::read_to_string;
+use std::ops::RangeInclusive;
 use std::path::Path;
 
+mod builder;
+mod mapping;
+
+pub use builder::*;
+pub use mapping::*;
+
 pub fn build() {
-    let out_dir = var_os("OUT_DIR").unwrap();
-    let out_dir = Path::new(&out_dir).join("CODEPAGE");
-    create_dir_all(&out_dir).unwrap();
-    for &code_page in KNOWN_CODE_PAGES {
-        let file = out_dir.join(format!("{}", code_page));
-        let mut file = File::create(file).unwrap();
-        file.write_all(&CodePage::generate(code_page).0).unwrap();
+    Builder::new().build().unwrap_or_else(|e| panic!("{e}"));
+}
+
+/// Reads a Unicode consortium mapping file and writes it as `CODEPAGE\NNN`.
+pub fn build_mapping(code_page: u16, mapping_file: impl AsRef<Path>) {
+    let mapping_file = mapping_file.as_ref();
+    let text = read_to_string(mapping_file).unwrap_or_else(|e| panic!("{}: {e}", mapping_file.display()));
+    let mapping = Mapping::parse(&text).unwrap_or_else(|e| panic!("{}: {e}", mapping_file.display()));
+    Builder::new().pages([]).custom_table(code_page, mapping).build().unwrap_or_else(|e| panic!("{e}"));
+}
+
+/// Writes a double-byte table as `CODEPAGE\NNN`.
+///
+/// No double-byte tables are bundled, the mapping has to come from elsewhere, e.g. [`build_dbcs_mapping`].
+pub fn build_dbcs(code_page: u16, mapping: impl IntoIterator<Item=(u16, char)>) {
+    Builder::new().pages([]).dbcs_table(code_page, mapping).build().unwrap_or_else(|e| panic!("{e}"));
+}
+
+/// Reads a Unicode consortium double-byte mapping file (`CP932.TXT` and the like) and writes it as `CODEPAGE\NNN`.
+pub fn build_dbcs_mapping(code_page: u16, mapping_file: impl AsRef<Path>) {
+    let mapping_file = mapping_file.as_ref();
+    let text = read_to_string(mapping_file).unwrap_or_else(|e| panic!("{}: {e}", mapping_file.display()));
+    let mapping = parse_dbcs_mapping(&text).unwrap_or_else(|e| panic!("{}: {e}", mapping_file.display()));
+    build_dbcs(code_page, mapping);
+}
+
+pub fn dbcs_lead_ranges(code_page: u16) -> Option<&'static [RangeInclusive<u8>]> {
+    match code_page {
+        932 => Some(&[0x81 ..= 0x9F, 0xE0 ..= 0xFC]),
+        936 | 949 | 950 => Some(&[0x81 ..= 0xFE]),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DbcsError {
+    InvalidLeadByte { lead: u8 },
+    OverlappingLeadBytes { lead: u8 },
+    TooBigChar { code: u16, c: char },
+    NonAsciiLowHalf { code: u16, c: char },
+    SingleByteAtLeadByte { code: u16 },
+    UnknownLeadByte { code: u16 },
+    InvalidTrailByte { code: u16 },
+    DuplicateCode { code: u16 },
+    TooManyReversePages,
+}
+
+impl Display for DbcsError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DbcsError::InvalidLeadByte { lead } => write!(f, "invalid lead byte {lead:02X}h"),
+            DbcsError::OverlappingLeadBytes { lead } => write!(f, "lead byte {lead:02X}h is in more than one range"),
+            DbcsError::TooBigChar { code, c } =>
+                write!(f, "code {code:04X}h: char U+{:04X} is outside the BMP, it needs to be preremapped", *c as u32),
+            DbcsError::NonAsciiLowHalf { code, c } =>
+                write!(f, "code {code:02X}h is mapped to U+{:04X}, but the low half should be ASCII", *c as u32),
+            DbcsError::SingleByteAtLeadByte { code } => write!(f, "single-byte code {code:02X}h is a lead byte"),
+            DbcsError::UnknownLeadByte { code } => write!(f, "code {code:04X}h has an unknown lead byte"),
+            DbcsError::InvalidTrailByte { code } => write!(f, "code {code:04X}h has an invalid trail byte"),
+            DbcsError::DuplicateCode { code } => write!(f, "code {code:04X}h is already mapped"),
+            DbcsError::TooManyReversePages => write!(f, "too many reverse lookup pages"),
+        }
     }
 }
 
-pub const KNOWN_CODE_PAGES: &[u16] = &[
-    437, 720, 737, 808, 850, 852, 855, 857, 858, 860,
-    861, 862, 863, 864, 865, 866, 869, 874,
-    912, 915,
-];
+impl Error for DbcsError { }
+
+pub fn generate_dbcs(
+    lead_ranges: &[RangeInclusive<u8>],
+    mapping: impl IntoIterator<Item=(u16, char)>
+) -> Result<Vec<u8>, DbcsError> {
+    let mut lead_bytes = [0u8; 256];
+    let mut rows_count = 0u8;
+    for lead in lead_ranges.iter().cloned().flatten() {
+        if lead >> 7 == 0 { return Err(DbcsError::InvalidLeadByte { lead }); }
+        if lead_bytes[lead as usize] != 0 { return Err(DbcsError::OverlappingLeadBytes { lead }); }
+        // At most 128 distinct lead bytes, cannot overflow.
+        rows_count += 1;
+        lead_bytes[lead as usize] = rows_count;
+    }
+    let mut upper_half = [0u16; 128];
+    let mut rows = vec![0u16; rows_count as usize * DBCS_ROW_SIZE / 2];
+    let mut reverse = vec![[0u16; 256]; 256];
+    for (code, c) in mapping {
+        let w: u16 = (c as u32).try_into().map_err(|_| DbcsError::TooBigChar { code, c })?;
+        if code >> 8 == 0 {
+            if code >> 7 == 0 {
+                if w != code { return Err(DbcsError::NonAsciiLowHalf { code, c }); }
+                continue;
+            }
+            if lead_bytes[code as usize] != 0 { return Err(DbcsError::SingleByteAtLeadByte { code }); }
+            if upper_half[(code & 0x7F) as usize] != 0 { return Err(DbcsError::DuplicateCode { code }); }
+            upper_half[(code & 0x7F) as usize] = w;
+        } else {
+            let row = lead_bytes[(code >> 8) as usize];
+            if row == 0 { return Err(DbcsError::UnknownLeadByte { code }); }
+            let trail = code as u8;
+            if trail < 0x40 { return Err(DbcsError::InvalidTrailByte { code }); }
+            let entry = &mut rows[(row - 1) as usize * DBCS_ROW_SIZE / 2 + (trail - 0x40) as usize];
+            if *entry != 0 { return Err(DbcsError::DuplicateCode { code }); }
+            *entry = w;
+        }
+        if w >> 7 == 0 { continue; }
+        let reverse = &mut reverse[(w >> 8) as usize][(w & 0xFF) as usize];
+        if *reverse == 0 || *reverse > code {
+            *reverse = code;
+        }
+    }
+    let mut reverse_pages_index = [0u8; 256];
+    let mut reverse_pages = Vec::new();
+    for (high, page) in reverse.iter().enumerate().filter(|(_, page)| page.iter().any(|&x| x != 0)) {
+        reverse_pages.push(page);
+        reverse_pages_index[high] = reverse_pages.len().try_into().map_err(|_| DbcsError::TooManyReversePages)?;
+    }
+    let mut res = Vec::with_capacity(DbcsCodePage::size(rows_count, reverse_pages.len() as u8));
+    res.extend_from_slice(&lead_bytes);
+    res.extend(upper_half.iter().flat_map(|&w| w.to_be_bytes()));
+    res.extend_from_slice(&reverse_pages_index);
+    res.push(rows_count);
+    res.push(reverse_pages.len() as u8);
+    debug_assert_eq!(res.len(), DBCS_HEADER_SIZE);
+    res.extend(rows.iter().flat_map(|&w| w.to_be_bytes()));
+    res.extend(reverse_pages.iter().flat_map(|page| page.iter()).flat_map(|&w| w.to_be_bytes()));
+    debug_assert_eq!(res.len() - DBCS_HEADER_SIZE - rows.len() * 2, reverse_pages.len() * DBCS_REVERSE_PAGE_SIZE);
+    assert!(DbcsCodePage::new(&res).is_some());
+    Ok(res)
+}
 
-#[derive(Copy, Clone)]
-enum Filled { None, Half, Full }
+pub const KNOWN_CODE_PAGES: &[u16] = tables::KNOWN_CODE_PAGES;
 
 pub trait CodePageGenExt {
     fn generate(code_page: u16) -> Self;
@@ -33,464 +151,45 @@ pub trait CodePageGenExt {
 
 impl CodePageGenExt for CodePage {
     fn generate(code_page: u16) -> CodePage {
-        let (base_table, hash_param) = base_table_and_hash_param(code_page);
-        let mut res: [MaybeUninit<u8>; 512] = unsafe { MaybeUninit::uninit().assume_init() };
-        res[510].write(hash_param as u8);
-        res[511].write((hash_param >> 8) as u8);
-        let base_table = base_table.iter().copied().map(|c| {
-            if c == '?' { return 0; }
-            let c: u16 = (c as u32).try_into()
-                .expect("too big char, bit needs to be preremapped");
-            c
+        let (upper_half, hash_param) = tables::upper_half_and_hash_param(code_page).expect("unknown code page");
+        let hash_param = hash_param.unwrap_or_else(|| {
+            find_hash_param(upper_half).unwrap_or_else(|e| panic!("code page {code_page}: {e}"))
         });
-        let mut first_part = &mut res[.. 256];
-        for byte in base_table.clone().flat_map(|w| [(w >> 8) as u8, w as u8]) {
-            first_part[0].write(byte);
-            first_part = &mut first_part[1 ..];
-        }
-        let second_part = &mut res[256 ..];
-        let mut filled = [Filled::None; 127];
-        for (i, w) in base_table.into_iter().enumerate().filter(|&(_, w)| w != 0) {
-            let hash = hash(w, hash_param);
-            debug_assert!((hash >> 7) == 0 && hash != 0x7F, "invalid hash");
-            let filled = &mut filled[hash as usize];
-            match filled {
-                Filled::None => {
-                    second_part[(2 * hash) as usize].write(i as u8);
-                    *filled = Filled::Half;
-                },
-                Filled::Half => {
-                    second_part[(2 * hash + 1) as usize].write(i as u8);
-                    *filled = Filled::Full;
-                },
-                Filled::Full => unreachable!("invalid hash / base table"),
-            }
-        }
-        for (i, filled) in filled.into_iter().enumerate() {
-            match filled {
-                Filled::None => {
-                    second_part[2 * i].write(128);
-                    second_part[2 * i + 1].write(128);
-                },
-                Filled::Half => {
-                    second_part[2 * i + 1].write(128);
-                },
-                Filled::Full => { }
-            }
+        let code_page = CodePage::with_hash_param(upper_half, hash_param)
+            .with_low_half_overrides(tables::low_half_overrides(code_page));
+        if let Some(inconsistency) = code_page.verify().next() {
+            panic!("inconsistent table: {inconsistency}");
         }
-        CodePage(unsafe { transmute(res) })
+        code_page
     }
 }
 
-fn base_table_and_hash_param(code_page: u16) -> (&'static [char; 128], u16) {
-    match code_page {
-        437 => (&CP437, 0x9F8D),
-        720 => (&CP720, 0x8EAC),
-        737 => (&CP737, 0x9AF8),
-        808 => (&CP808, 0x8398),
-        850 => (&CP850, 0x80F1),
-        852 => (&CP852, 0xF5F4),
-        855 => (&CP855, 0x808F),
-        857 => (&CP857, 0x80EE),
-        858 => (&CP858, 0x80F1),
-        860 => (&CP860, 0x9FCD),
-        861 => (&CP861, 0x83AD),
-        862 => (&CP862, 0xBB95),
-        863 => (&CP863, 0xEAC8),
-        864 => (&CP864, 0x80A2),
-        865 => (&CP865, 0x9F8D),
-        866 => (&CP866, 0x8398),
-        869 => (&CP869, 0x8B8F),
-        874 => (&CP874, 0x8080),
-        912 => (&CP912, 0x8086),
-        915 => (&CP915, 0x8080),
-        _ => panic!("unknow code page"),
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NoParamError {
+    pub too_big_char: char,
+}
+
+impl Display for NoParamError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "no hash param exists for char {:?} outside the BMP, it needs to be preremapped", self.too_big_char)
     }
 }
- 
-const CP437: [char; 128] = [
-    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç',
-    'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
-    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù',
-    'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ',
-    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º',
-    '¿', '⌐', '¬', '½', '¼', '¡', '«', '»',
-    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
-    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
-    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
-    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
-    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
-    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ',
-    'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
-    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈',
-    '°', '∙', '·', '√', 'ⁿ', '²', '■', ' ',
-];
-
-const CP720: [char; 128] = [
-    '\u{003F}', '\u{003F}', '\u{00E9}', '\u{00E2}', '\u{003F}', '\u{00E0}', '\u{003F}', '\u{00E7}',
-    '\u{00EA}', '\u{00EB}', '\u{00E8}', '\u{00EF}', '\u{00EE}', '\u{003F}', '\u{003F}', '\u{003F}',
-    '\u{003F}', '\u{0651}', '\u{0652}', '\u{00F4}', '\u{00A4}', '\u{0640}', '\u{00FB}', '\u{00F9}',
-    '\u{0621}', '\u{0622}', '\u{0623}', '\u{0624}', '\u{00A3}', '\u{0625}', '\u{0626}', '\u{0627}',
-    '\u{0628}', '\u{0629}', '\u{062A}', '\u{062B}', '\u{062C}', '\u{062D}', '\u{062E}', '\u{062F}',
-    '\u{0630}', '\u{0631}', '\u{0632}', '\u{0633}', '\u{0634}', '\u{0635}', '\u{00AB}', '\u{00BB}',
-    '\u{2591}', '\u{2592}', '\u{2593}', '\u{2502}', '\u{2524}', '\u{2561}', '\u{2562}', '\u{2556}',
-    '\u{2555}', '\u{2563}', '\u{2551}', '\u{2557}', '\u{255D}', '\u{255C}', '\u{255B}', '\u{2510}',
-    '\u{2514}', '\u{2534}', '\u{252C}', '\u{251C}', '\u{2500}', '\u{253C}', '\u{255E}', '\u{255F}',
-    '\u{255A}', '\u{2554}', '\u{2569}', '\u{2566}', '\u{2560}', '\u{2550}', '\u{256C}', '\u{2567}',
-    '\u{2568}', '\u{2564}', '\u{2565}', '\u{2559}', '\u{2558}', '\u{2552}', '\u{2553}', '\u{256B}',
-    '\u{256A}', '\u{2518}', '\u{250C}', '\u{2588}', '\u{2584}', '\u{258C}', '\u{2590}', '\u{2580}',
-    '\u{0636}', '\u{0637}', '\u{0638}', '\u{0639}', '\u{063A}', '\u{0641}', '\u{00B5}', '\u{0642}',
-    '\u{0643}', '\u{0644}', '\u{0645}', '\u{0646}', '\u{0647}', '\u{0648}', '\u{0649}', '\u{064A}',
-    '\u{2261}', '\u{064B}', '\u{064C}', '\u{064D}', '\u{064E}', '\u{064F}', '\u{0650}', '\u{2248}',
-    '\u{00B0}', '\u{2219}', '\u{00B7}', '\u{221A}', '\u{207F}', '\u{00B2}', '\u{25A0}', '\u{00A0}',
-];
-
-const CP737: [char; 128] = [
-    'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ',
-    'Ι', 'Κ', 'Λ', 'Μ', 'Ν', 'Ξ', 'Ο', 'Π',
-    'Ρ', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω',
-    'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ',
-    'ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π',
-    'ρ', 'σ', 'ς', 'τ', 'υ', 'φ', 'χ', 'ψ',
-    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
-    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
-    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
-    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
-    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
-    'ω', 'ά', 'έ', 'ή', 'ϊ', 'ί', 'ό', 'ύ',
-    'ϋ', 'ώ', 'Ά', 'Έ', 'Ή', 'Ί', 'Ό', 'Ύ',
-    'Ώ', '±', '≥', '≤', 'Ϊ', 'Ϋ', '÷', '≈',
-    '°', '∙', '·', '√', 'ⁿ', '²', '■', ' ',
-];
-
-const CP808: [char; 128] = [
-    'А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ж', 'З',
-    'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П',
-    'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч',
-    'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я',
-    'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з',
-    'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п',
-    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
-    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
-    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
-    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
-    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
-    'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч',
-    'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я',
-    'Ё', 'ё', 'Є', 'є', 'Ї', 'ї', 'Ў', 'ў',
-    '°', '∙', '·', '√', '№', '€', '■', ' ',
-];
-
-const CP850: [char; 128] = [
-    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç',
-    'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
-    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù',
-    'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', 'ƒ',
-    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º',
-    '¿', '®', '¬', '½', '¼', '¡', '«', '»',
-    '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À',
-    '©', '╣', '║', '╗', '╝', '¢', '¥', '┐',
-    '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤',
-    'ð', 'Ð', 'Ê', 'Ë', 'È', 'ı', 'Í', 'Î',
-    'Ï', '┘', '┌', '█', '▄', '¦', 'Ì', '▀',
-    'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', 'þ',
-    'Þ', 'Ú', 'Û', 'Ù', 'ý', 'Ý', '¯', '´',
-    '­', '±', '‗', '¾', '¶', '§', '÷', '¸',
-    '°', '¨', '·', '¹', '³', '²', '■', ' ',
-];
-
-const CP852: [char; 128] = [
-    'Ç', 'ü', 'é', 'â', 'ä', 'ů', 'ć', 'ç',
-    'ł', 'ë', 'Ő', 'ő', 'î', 'Ź', 'Ä', 'Ć',
-    'É', 'Ĺ', 'ĺ', 'ô', 'ö', 'Ľ', 'ľ', 'Ś',
-    'ś', 'Ö', 'Ü', 'Ť', 'ť', 'Ł', '×', 'č',
-    'á', 'í', 'ó', 'ú', 'Ą', 'ą', 'Ž', 'ž',
-    'Ę', 'ę', '¬', 'ź', 'Č', 'ş', '«', '»',
-    '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'Ě',
-    'Ş', '╣', '║', '╗', '╝', 'Ż', 'ż', '┐',
-    '└', '┴', '┬', '├', '─', '┼', 'Ă', 'ă',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤',
-    'đ', 'Đ', 'Ď', 'Ë', 'ď', 'Ň', 'Í', 'Î',
-    'ě', '┘', '┌', '█', '▄', 'Ţ', 'Ů', '▀',
-    'Ó', 'ß', 'Ô', 'Ń', 'ń', 'ň', 'Š', 'š',
-    'Ŕ', 'Ú', 'ŕ', 'Ű', 'ý', 'Ý', 'ţ', '´',
-    '­', '˝', '˛', 'ˇ', '˘', '§', '÷', '¸',
-    '°', '¨', '˙', 'ű', 'Ř', 'ř', '■', ' ',
-];
-
-const CP855: [char; 128] = [
-    'ђ', 'Ђ', 'ѓ', 'Ѓ', 'ё', 'Ё', 'є', 'Є',
-    'ѕ', 'Ѕ', 'і', 'І', 'ї', 'Ї', 'ј', 'Ј',
-    'љ', 'Љ', 'њ', 'Њ', 'ћ', 'Ћ', 'ќ', 'Ќ',
-    'ў', 'Ў', 'џ', 'Џ', 'ю', 'Ю', 'ъ', 'Ъ',
-    'а', 'А', 'б', 'Б', 'ц', 'Ц', 'д', 'Д',
-    'е', 'Е', 'ф', 'Ф', 'г', 'Г', '«', '»',
-    '░', '▒', '▓', '│', '┤', 'х', 'Х', 'и',
-    'И', '╣', '║', '╗', '╝', 'й', 'Й', '┐',
-    '└', '┴', '┬', '├', '─', '┼', 'к', 'К',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤',
-    'л', 'Л', 'м', 'М', 'н', 'Н', 'о', 'О',
-    'п', '┘', '┌', '█', '▄', 'П', 'я', '▀',
-    'Я', 'р', 'Р', 'с', 'С', 'т', 'Т', 'у',
-    'У', 'ж', 'Ж', 'в', 'В', 'ь', 'Ь', '№',
-    '­', 'ы', 'Ы', 'з', 'З', 'ш', 'Ш', 'э',
-    'Э', 'щ', 'Щ', 'ч', 'Ч', '§', '■', ' ',
-];
-
-const CP857: [char; 128] = [
-    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç',
-    'ê', 'ë', 'è', 'ï', 'î', 'ı', 'Ä', 'Å',
-    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù',
-    'İ', 'Ö', 'Ü', 'ø', '£', 'Ø', 'Ş', 'ş',
-    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'Ğ', 'ğ',
-    '¿', '®', '¬', '½', '¼', '¡', '«', '»',
-    '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À',
-    '©', '╣', '║', '╗', '╝', '¢', '¥', '┐',
-    '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤',
-    'º', 'ª', 'Ê', 'Ë', 'È', '?', 'Í', 'Î',
-    'Ï', '┘', '┌', '█', '▄', '¦', 'Ì', '▀',
-    'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', '?',
-    '×', 'Ú', 'Û', 'Ù', 'ì', 'ÿ', '¯', '´',
-    '­', '±', '?', '¾', '¶', '§', '÷', '¸',
-    '°', '¨', '·', '¹', '³', '²', '■', ' ',
-];
-
-const CP858: [char; 128] = [
-    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç',
-    'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
-    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù',
-    'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '×', 'ƒ',
-    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º',
-    '¿', '®', '¬', '½', '¼', '¡', '«', '»',
-    '░', '▒', '▓', '│', '┤', 'Á', 'Â', 'À',
-    '©', '╣', '║', '╗', '╝', '¢', '¥', '┐',
-    '└', '┴', '┬', '├', '─', '┼', 'ã', 'Ã',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '¤',
-    'ð', 'Ð', 'Ê', 'Ë', 'È', '€', 'Í', 'Î',
-    'Ï', '┘', '┌', '█', '▄', '¦', 'Ì', '▀',
-    'Ó', 'ß', 'Ô', 'Ò', 'õ', 'Õ', 'µ', 'þ',
-    'Þ', 'Ú', 'Û', 'Ù', 'ý', 'Ý', '¯', '´',
-    '­', '±', '‗', '¾', '¶', '§', '÷', '¸',
-    '°', '¨', '·', '¹', '³', '²', '■', ' ',
-];
-
-const CP860: [char; 128] = [
-    'Ç', 'ü', 'é', 'â', 'ã', 'à', 'Á', 'ç',
-    'ê', 'Ê', 'è', 'Í', 'Ô', 'ì', 'Ã', 'Â',
-    'É', 'À', 'È', 'ô', 'õ', 'ò', 'Ú', 'ù',
-    'Ì', 'Õ', 'Ü', '¢', '£', 'Ù', '₧', 'Ó',
-    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º',
-    '¿', 'Ò', '¬', '½', '¼', '¡', '«', '»',
-    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
-    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
-    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
-    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
-    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
-    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ',
-    'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
-    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈',
-    '°', '∙', '·', '√', 'ⁿ', '²', '■', ' ',
-];
-
-const CP861: [char; 128] = [
-    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç',
-    'ê', 'ë', 'è', 'Ð', 'ð', 'Þ', 'Ä', 'Å',
-    'É', 'æ', 'Æ', 'ô', 'ö', 'þ', 'û', 'Ý',
-    'ý', 'Ö', 'Ü', 'ø', '£', 'Ø', '₧', 'ƒ',
-    'á', 'í', 'ó', 'ú', 'Á', 'Í', 'Ó', 'Ú',
-    '¿', '⌐', '¬', '½', '¼', '¡', '«', '»',
-    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
-    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
-    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
-    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
-    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
-    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ',
-    'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
-    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈',
-    '°', '∙', '·', '√', 'ⁿ', '²', '■', ' ',
-];
-
-const CP862: [char; 128] = [
-    'א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח',
-    'ט', 'י', 'ך', 'כ', 'ל', 'ם', 'מ', 'ן',
-    'נ', 'ס', 'ע', 'ף', 'פ', 'ץ', 'צ', 'ק',
-    'ר', 'ש', 'ת', '¢', '£', '¥', '₧', 'ƒ',
-    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º',
-    '¿', '⌐', '¬', '½', '¼', '¡', '«', '»',
-    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
-    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
-    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
-    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
-    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
-    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ',
-    'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
-    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈',
-    '°', '∙', '·', '√', 'ⁿ', '²', '■', ' ',
-];
-
-const CP863: [char; 128] = [
-    'Ç', 'ü', 'é', 'â', 'Â', 'à', '¶', 'ç',
-    'ê', 'ë', 'è', 'ï', 'î', '‗', 'À', '§',
-    'É', 'È', 'Ê', 'ô', 'Ë', 'Ï', 'û', 'ù',
-    '¤', 'Ô', 'Ü', '¢', '£', 'Ù', 'Û', 'ƒ',
-    '¦', '´', 'ó', 'ú', '¨', '¸', '³', '¯',
-    'Î', '⌐', '¬', '½', '¼', '¾', '«', '»',
-    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
-    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
-    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
-    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
-    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
-    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ',
-    'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
-    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈',
-    '°', '∙', '·', '√', 'ⁿ', '²', '■', ' ',
-];
-
-const CP864: [char; 128] = [
-    '\u{00B0}', '\u{00B7}', '\u{2219}', '\u{221A}', '\u{2592}', '\u{2500}', '\u{2502}', '\u{253C}',
-    '\u{2524}', '\u{252C}', '\u{251C}', '\u{2534}', '\u{2510}', '\u{250C}', '\u{2514}', '\u{2518}',
-    '\u{03B2}', '\u{221E}', '\u{03C6}', '\u{00B1}', '\u{00BD}', '\u{00BC}', '\u{2248}', '\u{00AB}',
-    '\u{00BB}', '\u{FEF7}', '\u{FEF8}', '\u{003F}', '\u{003F}', '\u{FEFB}', '\u{FEFC}', '\u{003F}',
-    '\u{00A0}', '\u{00AD}', '\u{FE82}', '\u{00A3}', '\u{00A4}', '\u{FE84}', '\u{003F}', '\u{003F}',
-    '\u{FE8E}', '\u{FE8F}', '\u{FE95}', '\u{FE99}', '\u{060C}', '\u{FE9D}', '\u{FEA1}', '\u{FEA5}',
-    '\u{0660}', '\u{0661}', '\u{0662}', '\u{0663}', '\u{0664}', '\u{0665}', '\u{0666}', '\u{0667}',
-    '\u{0668}', '\u{0669}', '\u{FED1}', '\u{061B}', '\u{FEB1}', '\u{FEB5}', '\u{FEB9}', '\u{061F}',
-    '\u{00A2}', '\u{FE80}', '\u{FE81}', '\u{FE83}', '\u{FE85}', '\u{FECA}', '\u{FE8B}', '\u{FE8D}',
-    '\u{FE91}', '\u{FE93}', '\u{FE97}', '\u{FE9B}', '\u{FE9F}', '\u{FEA3}', '\u{FEA7}', '\u{FEA9}',
-    '\u{FEAB}', '\u{FEAD}', '\u{FEAF}', '\u{FEB3}', '\u{FEB7}', '\u{FEBB}', '\u{FEBF}', '\u{FEC1}',
-    '\u{FEC5}', '\u{FECB}', '\u{FECF}', '\u{00A6}', '\u{00AC}', '\u{00F7}', '\u{00D7}', '\u{FEC9}',
-    '\u{0640}', '\u{FED3}', '\u{FED7}', '\u{FEDB}', '\u{FEDF}', '\u{FEE3}', '\u{FEE7}', '\u{FEEB}',
-    '\u{FEED}', '\u{FEEF}', '\u{FEF3}', '\u{FEBD}', '\u{FECC}', '\u{FECE}', '\u{FECD}', '\u{FEE1}',
-    '\u{FE7D}', '\u{0651}', '\u{FEE5}', '\u{FEE9}', '\u{FEEC}', '\u{FEF0}', '\u{FEF2}', '\u{FED0}',
-    '\u{FED5}', '\u{FEF5}', '\u{FEF6}', '\u{FEDD}', '\u{FED9}', '\u{FEF1}', '\u{25A0}', '\u{003F}',
-];
-
-const CP865: [char; 128] = [
-    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç',
-    'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
-    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù',
-    'ÿ', 'Ö', 'Ü', 'ø', '£', 'Ø', '₧', 'ƒ',
-    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º',
-    '¿', '⌐', '¬', '½', '¼', '¡', '«', '¤',
-    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
-    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
-    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
-    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
-    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
-    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ',
-    'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
-    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈',
-    '°', '∙', '·', '√', 'ⁿ', '²', '■', ' ',
-];
-
-const CP866: [char; 128] = [
-    'А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ж', 'З',
-    'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П',
-    'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч',
-    'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я',
-    'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з',
-    'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п',
-    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖',
-    '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
-    '└', '┴', '┬', '├', '─', '┼', '╞', '╟',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
-    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫',
-    '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
-    'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч',
-    'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я',
-    'Ё', 'ё', 'Є', 'є', 'Ї', 'ї', 'Ў', 'ў',
-    '°', '∙', '·', '√', '№', '¤', '■', ' ',
-];
-
-const CP869: [char; 128] = [
-    '?', '?', '?', '?', '?', '?', 'Ά', '?',
-    '·', '¬', '¦', '‘', '’', 'Έ', '―', 'Ή',
-    'Ί', 'Ϊ', 'Ό', '?', '?', 'Ύ', 'Ϋ', '©',
-    'Ώ', '²', '³', 'ά', '£', 'έ', 'ή', 'ί',
-    'ϊ', 'ΐ', 'ό', 'ύ', 'Α', 'Β', 'Γ', 'Δ',
-    'Ε', 'Ζ', 'Η', '½', 'Θ', 'Ι', '«', '»',
-    '░', '▒', '▓', '│', '┤', 'Κ', 'Λ', 'Μ',
-    'Ν', '╣', '║', '╗', '╝', 'Ξ', 'Ο', '┐',
-    '└', '┴', '┬', '├', '─', '┼', 'Π', 'Ρ',
-    '╚', '╔', '╩', '╦', '╠', '═', '╬', 'Σ',
-    'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', 'α', 'β',
-    'γ', '┘', '┌', '█', '▄', 'δ', 'ε', '▀',
-    'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν',
-    'ξ', 'ο', 'π', 'ρ', 'σ', 'ς', 'τ', '΄',
-    '­', '±', 'υ', 'φ', 'χ', '§', 'ψ', '΅',
-    '°', '¨', 'ω', 'ϋ', 'ΰ', 'ώ', '■', ' ',
-];
-
-const CP874: [char; 128] = [
-    '\u{20AC}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{2026}', '\u{003F}', '\u{003F}',
-    '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}',
-    '\u{003F}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
-    '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}',
-    '\u{00A0}', '\u{0E01}', '\u{0E02}', '\u{0E03}', '\u{0E04}', '\u{0E05}', '\u{0E06}', '\u{0E07}',
-    '\u{0E08}', '\u{0E09}', '\u{0E0A}', '\u{0E0B}', '\u{0E0C}', '\u{0E0D}', '\u{0E0E}', '\u{0E0F}',
-    '\u{0E10}', '\u{0E11}', '\u{0E12}', '\u{0E13}', '\u{0E14}', '\u{0E15}', '\u{0E16}', '\u{0E17}',
-    '\u{0E18}', '\u{0E19}', '\u{0E1A}', '\u{0E1B}', '\u{0E1C}', '\u{0E1D}', '\u{0E1E}', '\u{0E1F}',
-    '\u{0E20}', '\u{0E21}', '\u{0E22}', '\u{0E23}', '\u{0E24}', '\u{0E25}', '\u{0E26}', '\u{0E27}',
-    '\u{0E28}', '\u{0E29}', '\u{0E2A}', '\u{0E2B}', '\u{0E2C}', '\u{0E2D}', '\u{0E2E}', '\u{0E2F}',
-    '\u{0E30}', '\u{0E31}', '\u{0E32}', '\u{0E33}', '\u{0E34}', '\u{0E35}', '\u{0E36}', '\u{0E37}',
-    '\u{0E38}', '\u{0E39}', '\u{0E3A}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{0E3F}',
-    '\u{0E40}', '\u{0E41}', '\u{0E42}', '\u{0E43}', '\u{0E44}', '\u{0E45}', '\u{0E46}', '\u{0E47}',
-    '\u{0E48}', '\u{0E49}', '\u{0E4A}', '\u{0E4B}', '\u{0E4C}', '\u{0E4D}', '\u{0E4E}', '\u{0E4F}',
-    '\u{0E50}', '\u{0E51}', '\u{0E52}', '\u{0E53}', '\u{0E54}', '\u{0E55}', '\u{0E56}', '\u{0E57}',
-    '\u{0E58}', '\u{0E59}', '\u{0E5A}', '\u{0E5B}', '\u{003F}', '\u{003F}', '\u{003F}', '\u{003F}',
-];
-
-const CP912: [char; 128] = [
-    '', '', '', '', '', '', '', '',
-    '', '', '', '', '', '', '', '',
-    '', '', '', '', '', '', '', '',
-    '', '', '', '', '', '', '', '',
-    ' ', 'Ą', '˘', 'Ł', '¤', 'Ľ', 'Ś', '§',
-    '¨', 'Š', 'Ş', 'Ť', 'Ź', '­', 'Ž', 'Ż',
-    '°', 'ą', '˛', 'ł', '´', 'ľ', 'ś', 'ˇ',
-    '¸', 'š', 'ş', 'ť', 'ź', '˝', 'ž', 'ż',
-    'Ŕ', 'Á', 'Â', 'Ă', 'Ä', 'Ĺ', 'Ć', 'Ç',
-    'Č', 'É', 'Ę', 'Ë', 'Ě', 'Í', 'Î', 'Ď',
-    'Đ', 'Ń', 'Ň', 'Ó', 'Ô', 'Ő', 'Ö', '×',
-    'Ř', 'Ů', 'Ú', 'Ű', 'Ü', 'Ý', 'Ţ', 'ß',
-    'ŕ', 'á', 'â', 'ă', 'ä', 'ĺ', 'ć', 'ç',
-    'č', 'é', 'ę', 'ë', 'ě', 'í', 'î', 'ď',
-    'đ', 'ń', 'ň', 'ó', 'ô', 'ő', 'ö', '÷',
-    'ř', 'ů', 'ú', 'ű', 'ü', 'ý', 'ţ', '˙',
-];
-
-const CP915: [char; 128] = [
-    '', '', '', '', '', '', '', '',
-    '', '', '', '', '', '', '', '',
-    '', '', '', '', '', '', '', '',
-    '', '', '', '', '', '', '', '',
-    ' ', 'Ё', 'Ђ', 'Ѓ', 'Є', 'Ѕ', 'І', 'Ї',
-    'Ј', 'Љ', 'Њ', 'Ћ', 'Ќ', '­', 'Ў', 'Џ',
-    'А', 'Б', 'В', 'Г', 'Д', 'Е', 'Ж', 'З',
-    'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П',
-    'Р', 'С', 'Т', 'У', 'Ф', 'Х', 'Ц', 'Ч',
-    'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я',
-    'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з',
-    'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п',
-    'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч',
-    'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я',
-    '№', 'ё', 'ђ', 'ѓ', 'є', 'ѕ', 'і', 'ї',
-    'ј', 'љ', 'њ', 'ћ', 'ќ', '§', 'ў', 'џ',
-];
+
+impl Error for NoParamError { }
+
+pub fn find_hash_param(upper_half: &[char; 128]) -> Result<u16, NoParamError> {
+    if let Some(&too_big_char) = upper_half.iter().find(|&&c| (c as u32) >> 16 != 0) {
+        return Err(NoParamError { too_big_char });
+    }
+    Ok(CodePage::find_hash_param(upper_half).unwrap())
+}
 
 #[cfg(test)]
 mod test {
     use crate::*;
+    use dos_cp::{DbcsChar, DecodeError, DecodeExt, EncodeError, Encoded, EscapeStyle, MAX_REPLACEMENT_LEN, Unmappable};
+    use dos_cp::{FormatError, Inconsistency, code_page_by_name, hash, code_page_name, include_code_page};
+    use dos_cp::{CODE_PAGE_ARCHIVE_ENTRY_SIZE, CODE_PAGE_ARCHIVE_HEADER_SIZE, CodePageKind, file_header};
     use quickcheck::{Arbitrary, Gen, TestResult};
     use quickcheck_macros::quickcheck;
 
@@ -512,8 +211,10 @@ mod test {
 
     #[quickcheck]
     fn low_half_transform_is_trivial(code_page: KnownCodePage) -> bool {
-        let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
-        (0 .. 128).all(|a| {
+        let code_page_n = KNOWN_CODE_PAGES[code_page.0 as usize];
+        let low_half = tables::low_half_overrides(code_page_n);
+        let code_page = CodePage::generate(code_page_n);
+        (0 .. 128).filter(|&a| low_half.iter().all(|&(b, _)| b != a)).all(|a| {
             let u = a as char;
             let u_ = char::from_u32(a as u32).unwrap();
             let a_t = code_page.to_char(a).unwrap();
@@ -522,6 +223,538 @@ mod test {
         })
     }
 
+    #[quickcheck]
+    fn low_half_overrides_are_applied(code_page: KnownCodePage) -> bool {
+        let code_page_n = KNOWN_CODE_PAGES[code_page.0 as usize];
+        let low_half = tables::low_half_overrides(code_page_n);
+        let code_page = CodePage::generate(code_page_n);
+        code_page.has_ascii_low_half() == low_half.is_empty() && low_half.iter().all(|&(b, c)| {
+            code_page.to_char(b) == Some(c) && code_page.from_char(c) == Some(b)
+        })
+    }
+
+    #[test]
+    fn cp864_has_arabic_percent_sign() {
+        let code_page = CodePage::generate(864);
+        assert_eq!(code_page.as_bytes().len(), 896);
+        assert_eq!(code_page.to_char(0x25), Some('٪'));
+        assert_eq!(code_page.from_char('٪'), Some(0x25));
+        assert_eq!(code_page.from_char('%'), None);
+        assert_eq!(CodePage::generate(866).as_bytes().len(), 640);
+    }
+
+    #[test]
+    fn windows_code_pages() {
+        assert_eq!(CodePage::generate(1251).from_char('Я'), Some(0xDF));
+        assert_eq!(CodePage::generate(1252).to_char(0x80), Some('€'));
+        assert_eq!(CodePage::generate(1252).to_char(0x81), None);
+        assert_eq!(CodePage::generate(1258).to_char(0xCC), Some('\u{0300}'));
+    }
+
+    #[test]
+    fn national_code_pages() {
+        assert_eq!(CodePage::generate(775).from_char('Ą'), Some(0xB5));
+        assert_eq!(CodePage::generate(1125).from_char('Ґ'), Some(0xF2));
+        assert_eq!(CodePage::generate(1131).from_char('Ґ'), Some(0xFC));
+        assert_eq!(CodePage::generate(856).to_char(0x80), Some('א'));
+    }
+
+    #[quickcheck]
+    fn find_hash_param_is_valid(code_page: KnownCodePage) -> bool {
+        let (upper_half, _) = tables::upper_half_and_hash_param(KNOWN_CODE_PAGES[code_page.0 as usize]).unwrap();
+        let hash_param = find_hash_param(upper_half).unwrap();
+        CodePage::with_hash_param(upper_half, hash_param).verify().next().is_none()
+    }
+
+    // Chars differing only in bit 15 land in the same bucket under every param.
+    const COLLIDING_PAIRS: [char; 128] = {
+        let mut upper_half = ['?'; 128];
+        let mut i = 0;
+        while i < 64 {
+            let w = 0x1000 + i as u32 * 0x101;
+            let (Some(c), Some(d)) = (char::from_u32(w), char::from_u32(w | 0x8000)) else { panic!() };
+            upper_half[2 * i] = c;
+            upper_half[2 * i + 1] = d;
+            i += 1;
+        }
+        upper_half
+    };
+
+    fn colliding_pairs() -> [char; 128] {
+        COLLIDING_PAIRS
+    }
+
+    const CP867_UPPER_HALF: &[char; 128] = match tables::upper_half_and_hash_param(867) {
+        Some((upper_half, _)) => upper_half,
+        None => panic!(),
+    };
+
+    // The param search has to finish in const eval even when no param avoids the overflow area.
+    static CP867_SEARCHED: CodePage = CodePage::new(CP867_UPPER_HALF);
+
+    static COLLIDING_PAIRS_SEARCHED: CodePage = CodePage::new(&COLLIDING_PAIRS);
+
+    #[test]
+    fn overflowing_tables_are_built_in_statics() {
+        assert!(CP867_SEARCHED.verify().next().is_none());
+        assert!(COLLIDING_PAIRS_SEARCHED.verify().next().is_none());
+        for (i, &c) in CP867_UPPER_HALF.iter().enumerate() {
+            if c != '?' { assert_eq!(CP867_SEARCHED.from_char(c), Some(0x80 | i as u8)); }
+        }
+    }
+
+    #[test]
+    fn colliding_pairs_fit() {
+        let upper_half = colliding_pairs();
+        let hash_param = find_hash_param(&upper_half).unwrap();
+        let code_page = CodePage::with_hash_param(&upper_half, hash_param);
+        assert!(code_page.verify().next().is_none());
+        for (i, &c) in upper_half.iter().enumerate() {
+            assert_eq!(code_page.from_char(c), Some(0x80 | i as u8));
+        }
+    }
+
+    #[quickcheck]
+    fn any_table_fits(chars: Vec<u16>) -> bool {
+        let mut upper_half = ['?'; 128];
+        let mut used = Vec::new();
+        for w in chars {
+            let Some(c) = char::from_u32(w as u32) else { continue };
+            if (c as u32) < 0x80 || used.contains(&c) { continue; }
+            if used.len() == 128 { break; }
+            upper_half[used.len()] = c;
+            used.push(c);
+        }
+        let hash_param = find_hash_param(&upper_half).unwrap();
+        let code_page = CodePage::with_hash_param(&upper_half, hash_param);
+        code_page.verify().next().is_none() && used.iter().enumerate().all(|(i, &c)| code_page.from_char(c) == Some(0x80 | i as u8))
+    }
+
+    #[test]
+    fn find_hash_param_rejects_too_big_chars() {
+        let mut upper_half = colliding_pairs();
+        upper_half[5] = '\u{10000}';
+        let error = find_hash_param(&upper_half).unwrap_err();
+        assert_eq!(error, NoParamError { too_big_char: '\u{10000}' });
+    }
+
+    #[test]
+    fn overflowing_tables_are_generated() {
+        for code_page_n in [667, 790, 867, 895, 3021] {
+            let (upper_half, _) = tables::upper_half_and_hash_param(code_page_n).unwrap();
+            let hash_param = CodePage::find_hash_param(upper_half).unwrap();
+            assert!(CodePage::with_hash_param(upper_half, hash_param).verify().next().is_none());
+            let code_page = CodePage::generate(code_page_n);
+            for (i, &c) in upper_half.iter().enumerate() {
+                if c != '?' { assert_eq!(code_page.from_char(c), Some(0x80 | i as u8)); }
+            }
+        }
+        assert_eq!(CodePage::generate(667).from_char('Ą'), Some(0x8F));
+        assert_eq!(CodePage::generate(895).from_char('Č'), Some(0x80));
+    }
+
+    fn mapping_text(code_page: u16) -> String {
+        let (upper_half, _) = tables::upper_half_and_hash_param(code_page).unwrap();
+        let low_half = tables::low_half_overrides(code_page);
+        let mut text = format!("#\n#    Name:     cp{code_page} to Unicode table\n#\n");
+        for b in 0 .. 0x80u8 {
+            let c = low_half.iter().find(|&&(x, _)| x == b).map_or(b as char, |&(_, c)| c);
+            text.push_str(&format!("0x{b:02x}\t0x{:04x}\t#\n", c as u32));
+        }
+        for (i, &c) in upper_half.iter().enumerate() {
+            if c == '?' {
+                text.push_str(&format!("0x{:02X}\t\t#UNDEFINED\n", 0x80 | i));
+            } else {
+                text.push_str(&format!("0x{:02X}\t0x{:04X}\t# CHAR\n", 0x80 | i, c as u32));
+            }
+        }
+        text
+    }
+
+    #[quickcheck]
+    fn mapping_is_generate(code_page: KnownCodePage) -> bool {
+        let code_page = KNOWN_CODE_PAGES[code_page.0 as usize];
+        let mapping = Mapping::parse(&mapping_text(code_page)).unwrap().generate().unwrap();
+        let expected = CodePage::generate(code_page);
+        mapping.has_ascii_low_half() == expected.has_ascii_low_half() && (0 ..= 255).all(|b| {
+            let c = mapping.to_char(b);
+            c == expected.to_char(b) && c.is_none_or(|c| mapping.from_char(c) == Some(b))
+        })
+    }
+
+    #[test]
+    fn mapping_errors() {
+        assert_eq!(Mapping::parse("0x80\t0x00C7\n0x80\t0x00FC"), Err(MappingError::DuplicateByte { line: 2, byte: 0x80 }));
+        assert_eq!(Mapping::parse("0x80\t0x00C7\n0x81\t0x00C7"), Err(MappingError::DuplicateChar { line: 1, c: 'Ç' }));
+        assert_eq!(Mapping::parse("0x80\t0x0041"), Err(MappingError::DuplicateChar { line: 1, c: 'A' }));
+        assert_eq!(Mapping::parse("0x80\t0x00C7\n0x1A\t\t#UNDEFINED"), Err(MappingError::UndefinedLowHalfByte { line: 2, byte: 0x1A }));
+        assert_eq!(Mapping::parse("#\n0x100\t0x0041"), Err(MappingError::InvalidByte { line: 2 }));
+        assert_eq!(Mapping::parse("0x80\t0x1F600"), Err(MappingError::InvalidChar { line: 1 }));
+        assert_eq!(Mapping::parse("0x80\t0x0041+0x0301"), Err(MappingError::InvalidChar { line: 1 }));
+        assert_eq!(Mapping::parse("0x80 0x00C7 0x00FC"), Err(MappingError::InvalidLine { line: 1 }));
+        let mapping = Mapping::parse("0x25\t0x066A\n0x80\t0x00C7\n0x81\t\t#UNDEFINED\n").unwrap();
+        assert_eq!(mapping.low_half_overrides, [(0x25, '\u{066A}')]);
+        assert_eq!(&mapping.upper_half[.. 3], ['Ç', '?', '?']);
+    }
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("dos-cp-generator-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn builder_writes_selected_pages() {
+        let out_dir = temp_dir("pages");
+        let mapping = Mapping::parse("0x80\t0x0416\n0x81\t0x00C7\n").unwrap();
+        let rust_source = out_dir.join("code_pages.rs");
+        Builder::new().pages([866, 437]).custom_table(3000, mapping.clone()).out_dir(&out_dir).rust_source(&rust_source)
+            .build().unwrap();
+        let mut files: Vec<_> = std::fs::read_dir(&out_dir).unwrap().map(|x| x.unwrap().file_name()).collect();
+        files.sort();
+        assert_eq!(files, ["3000", "437", "866", "code_pages.rs"]);
+        for code_page in [866, 437] {
+            let file = std::fs::read(out_dir.join(code_page.to_string())).unwrap();
+            assert_eq!(CodePage::from_bytes(&file).unwrap().as_bytes(), CodePage::generate(code_page).as_bytes());
+        }
+        let custom = CodePage::from_bytes(&std::fs::read(out_dir.join("3000")).unwrap()).unwrap();
+        assert_eq!(custom.as_bytes(), mapping.generate().unwrap().as_bytes());
+        assert_eq!(custom.from_char('Ж'), Some(0x80));
+        let source = std::fs::read_to_string(&rust_source).unwrap();
+        assert!(source.contains("pub static CP866: dos_cp::CodePage"));
+        assert!(source.contains("        3000 => Some(&CP3000),"));
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    fn builder_reports_unknown_code_page() {
+        let out_dir = temp_dir("unknown");
+        let error = Builder::new().pages([866, 9999]).out_dir(&out_dir).build().unwrap_err();
+        assert!(matches!(error, BuildError::UnknownCodePage { code_page: 9999 }));
+        assert!(!out_dir.exists());
+    }
+
+    #[test]
+    fn builder_reports_inconsistent_table() {
+        let out_dir = temp_dir("inconsistent");
+        let mapping = Mapping { upper_half: ['?'; 128], low_half_overrides: vec![(0x25, 'A')] };
+        let error = Builder::new().pages([866]).custom_table(3000, mapping).out_dir(&out_dir).build().unwrap_err();
+        assert!(matches!(error, BuildError::Inconsistent {
+            code_page: 3000,
+            inconsistency: Inconsistency::DuplicateMapping { byte: 0x41, other: 0x25 }
+        }));
+        assert!(!out_dir.exists());
+        let mut upper_half = ['?'; 128];
+        upper_half[3] = 'é';
+        upper_half[9] = 'é';
+        let mapping = Mapping { upper_half, low_half_overrides: Vec::new() };
+        let error = Builder::new().custom_table(3000, mapping).out_dir(&out_dir).build().unwrap_err();
+        assert!(matches!(error, BuildError::Inconsistent {
+            code_page: 3000,
+            inconsistency: Inconsistency::DuplicateMapping { byte: 0x89, other: 0x83 }
+        }));
+        assert!(!out_dir.exists());
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate char")]
+    fn duplicate_chars_are_rejected() {
+        CodePage::new(&['é'; 128]);
+    }
+
+    #[test]
+    #[ignore = "builds a separate crate with a nested cargo"]
+    fn builder_rust_source_compiles() {
+        let dir = temp_dir("rust-source");
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        let mapping = Mapping::parse("0x80\t0x0416\n0x81\t0x00C7\n").unwrap();
+        Builder::new().pages([866, 864]).custom_table(3000, mapping).out_dir(dir.join("CODEPAGE"))
+            .rust_source(dir.join("src/code_pages.rs")).build().unwrap();
+        std::fs::write(dir.join("Cargo.toml"), format!(
+            "[package]\nname = \"rust-source\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+            [dependencies]\ndos-cp = {{ path = {:?}, default-features = false }}\n\n[workspace]\n",
+            Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+        )).unwrap();
+        std::fs::write(dir.join("src/main.rs"), "\
+            mod code_pages;\n\
+            fn main() {\n\
+                assert!(code_pages::code_page(437).is_none());\n\
+                assert_eq!(code_pages::CP3000.from_char('Ж'), Some(0x80));\n\
+                for n in [866, 864, 3000] {\n\
+                    println!(\"{n} {:?}\", code_pages::code_page(n).unwrap().as_bytes());\n\
+                }\n\
+            }\n\
+        ").unwrap();
+        let output = std::process::Command::new(std::env::var_os("CARGO").unwrap_or("cargo".into()))
+            .args(["run", "--quiet", "--offline", "--manifest-path"]).arg(dir.join("Cargo.toml"))
+            .env("CARGO_TARGET_DIR", dir.join("target"))
+            .output().unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        let custom = Mapping::parse("0x80\t0x0416\n0x81\t0x00C7\n").unwrap().generate().unwrap();
+        let expected = [(866, CodePage::generate(866)), (864, CodePage::generate(864)), (3000, custom)].iter()
+            .map(|(n, code_page)| format!("{n} {:?}\n", code_page.as_bytes())).collect::<String>();
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn builder_writes_dbcs_tables() {
+        let out_dir = temp_dir("dbcs");
+        let archive = out_dir.join("CODEPAGE.DAT");
+        let expected = generate_dbcs(dbcs_lead_ranges(932).unwrap(), dbcs_test_mapping()).unwrap();
+        Builder::new().pages([866]).dbcs_table(932, dbcs_test_mapping()).out_dir(&out_dir).build().unwrap();
+        let file = std::fs::read(out_dir.join("932")).unwrap();
+        assert_eq!(DbcsCodePage::from_bytes(&file).unwrap().as_bytes(), &expected[..]);
+        Builder::new().pages([866]).dbcs_table(932, dbcs_test_mapping()).archive(&archive).build().unwrap();
+        let archive = std::fs::read(&archive).unwrap();
+        assert_eq!(CodePage::from_archive(&archive, 866).unwrap().as_bytes(), CodePage::generate(866).as_bytes());
+        assert_eq!(CodePage::from_archive(&archive, 932).unwrap_err(), FormatError::KindMismatch { kind: CodePageKind::DoubleByte });
+        assert_eq!(DbcsCodePage::from_archive(&archive, 932).unwrap().as_bytes(), &expected[..]);
+        assert_eq!(DbcsCodePage::from_archive(&archive, 866).unwrap_err(), FormatError::KindMismatch { kind: CodePageKind::SingleByte });
+        std::fs::remove_dir_all(&out_dir).unwrap();
+        let error = Builder::new().pages([]).dbcs_table(866, []).out_dir(&out_dir).build().unwrap_err();
+        assert!(matches!(error, BuildError::UnknownCodePage { code_page: 866 }));
+        let error = Builder::new().pages([]).dbcs_table(932, [(0x8140, '\u{3000}'), (0x8140, '\u{3001}')])
+            .out_dir(&out_dir).build().unwrap_err();
+        assert!(matches!(error, BuildError::Dbcs { code_page: 932, error: DbcsError::DuplicateCode { code: 0x8140 } }));
+        assert!(!out_dir.exists());
+    }
+
+    #[test]
+    fn dbcs_mapping_is_parsed() {
+        let text = "#\n#    Name:     cp932 to Unicode table\n#\n\
+            0x41\t0x0041\t#LATIN CAPITAL LETTER A\n\
+            0x80\t0x0080\t#<control>\n\
+            0x81\t\t#DBCS LEAD BYTE\n\
+            0xA1\t0xFF61\t#HALFWIDTH IDEOGRAPHIC FULL STOP\n\
+            0x8140\t0x3000\t#IDEOGRAPHIC SPACE\n";
+        let mapping = parse_dbcs_mapping(text).unwrap();
+        assert_eq!(mapping, [(0x41, 'A'), (0x80, '\u{80}'), (0xA1, '｡'), (0x8140, '\u{3000}')]);
+        let code_page = generate_dbcs(dbcs_lead_ranges(932).unwrap(), mapping).unwrap();
+        let code_page = DbcsCodePage::new(&code_page).unwrap();
+        assert_eq!(code_page.from_char('\u{3000}'), Some(DbcsChar::Double(0x81, 0x40)));
+        assert_eq!(parse_dbcs_mapping("0x8140\t0x3000\t0x3001"), Err(MappingError::InvalidLine { line: 1 }));
+        assert_eq!(parse_dbcs_mapping("0x18140\t0x3000"), Err(MappingError::InvalidByte { line: 1 }));
+        assert_eq!(parse_dbcs_mapping("0x8140\t0xD800"), Err(MappingError::InvalidChar { line: 1 }));
+    }
+
+    #[test]
+    fn generate_dbcs_reports_invalid_mappings() {
+        let generate = |mapping: &[(u16, char)]| generate_dbcs(dbcs_lead_ranges(932).unwrap(), mapping.iter().copied());
+        assert_eq!(generate(&[(0x41, 'B')]), Err(DbcsError::NonAsciiLowHalf { code: 0x41, c: 'B' }));
+        assert_eq!(generate(&[(0x81, 'é')]), Err(DbcsError::SingleByteAtLeadByte { code: 0x81 }));
+        assert_eq!(generate(&[(0xA140, 'é')]), Err(DbcsError::UnknownLeadByte { code: 0xA140 }));
+        assert_eq!(generate(&[(0x8120, 'é')]), Err(DbcsError::InvalidTrailByte { code: 0x8120 }));
+        assert_eq!(generate(&[(0x8140, '\u{10000}')]), Err(DbcsError::TooBigChar { code: 0x8140, c: '\u{10000}' }));
+        assert_eq!(generate_dbcs(&[0x81 ..= 0x90, 0x90 ..= 0x9F], []), Err(DbcsError::OverlappingLeadBytes { lead: 0x90 }));
+        assert_eq!(generate_dbcs(&[0x70 ..= 0x9F], []), Err(DbcsError::InvalidLeadByte { lead: 0x70 }));
+    }
+
+    #[test]
+    fn mapping_generate_reports_errors() {
+        let mut upper_half = ['?'; 128];
+        upper_half[1] = 'é';
+        upper_half[4] = 'é';
+        let mapping = Mapping { upper_half, low_half_overrides: Vec::new() };
+        assert_eq!(mapping.generate().unwrap_err(), TableError::Inconsistent {
+            inconsistency: Inconsistency::DuplicateMapping { byte: 0x84, other: 0x81 }
+        });
+        let mapping = Mapping { upper_half: ['?'; 128], low_half_overrides: vec![(0x25, 'A')] };
+        assert_eq!(mapping.generate().unwrap_err(), TableError::Inconsistent {
+            inconsistency: Inconsistency::DuplicateMapping { byte: 0x41, other: 0x25 }
+        });
+        upper_half[4] = '\u{10000}';
+        let mapping = Mapping { upper_half, low_half_overrides: Vec::new() };
+        assert_eq!(mapping.generate().unwrap_err(), TableError::NoParam { error: NoParamError { too_big_char: '\u{10000}' } });
+    }
+
+    #[test]
+    fn builder_writes_archive() {
+        let out_dir = temp_dir("archive");
+        let archive = out_dir.join("CODEPAGE.DAT");
+        Builder::new().pages([866, 864, 667]).archive(&archive).build().unwrap();
+        let files: Vec<_> = std::fs::read_dir(&out_dir).unwrap().map(|x| x.unwrap().file_name()).collect();
+        assert_eq!(files, ["CODEPAGE.DAT"]);
+        let archive = std::fs::read(&archive).unwrap();
+        for code_page in [866, 864, 667] {
+            assert_eq!(CodePage::from_archive(&archive, code_page).unwrap().as_bytes(), CodePage::generate(code_page).as_bytes());
+        }
+        assert_eq!(CodePage::from_archive(&archive, 437).unwrap_err(), FormatError::MissingCodePage { code_page: 437 });
+        assert_eq!(CodePage::from_archive(&archive[.. 100], 667).unwrap_err(), FormatError::TooShort);
+        assert_eq!(CodePage::from_archive(&archive[4 ..], 866).unwrap_err(), FormatError::InvalidMagic);
+        let mut huge = archive.clone();
+        let file = CODE_PAGE_ARCHIVE_HEADER_SIZE + 3 * CODE_PAGE_ARCHIVE_ENTRY_SIZE;
+        huge[file + 8 .. file + 12].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(CodePage::from_archive(&huge, 866).unwrap_err(), FormatError::TooShort);
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    fn code_page_file_display() {
+        use dos_cp::{CodePageDir, CodePageFile};
+        let file = CodePageFile::Single { dir: CodePageDir::ProgramDir(b"C:\\APP"), code_page: 866 };
+        assert_eq!(file.to_string(), "C:\\APP\\CODEPAGE\\866");
+        assert_eq!(CodePageFile::Archive { dir: CodePageDir::PathVar(b"D:\\") }.to_string(), "D:\\CODEPAGE.DAT");
+        assert_eq!(CodePageFile::Archive { dir: CodePageDir::CurrentDir }.to_string(), "CODEPAGE.DAT");
+        assert_eq!(CodePageDir::PathVar(b"D:\\CP").to_string(), "D:\\CP (DOSCP_PATH)");
+    }
+
+    #[test]
+    fn ascii_code_page() {
+        let ascii = CodePage::ascii();
+        assert!(ascii.verify().next().is_none());
+        assert!((0x80 ..= 0xFF).all(|b| ascii.to_char(b).is_none()));
+        assert_eq!(ascii.to_char(b'A'), Some('A'));
+        assert_eq!(ascii.from_char('Я'), None);
+    }
+
+    const CP852: CodePage = include_code_page!(852);
+
+    const _: () = assert!(CP852.can_encode("Příliš žluťoučký kůň").is_ok());
+
+    #[test]
+    fn include_code_page_is_generate() {
+        assert_eq!(CP852.as_bytes(), CodePage::generate(852).as_bytes());
+    }
+
+    #[quickcheck]
+    fn new_is_equivalent_to_generate(code_page: KnownCodePage) -> bool {
+        let code_page_n = KNOWN_CODE_PAGES[code_page.0 as usize];
+        let (upper_half, _) = tables::upper_half_and_hash_param(code_page_n).unwrap();
+        let code_page = CodePage::new(upper_half).with_low_half_overrides(tables::low_half_overrides(code_page_n));
+        let expected = CodePage::generate(code_page_n);
+        (0 ..= 255).all(|b| {
+            let c = code_page.to_char(b);
+            c == expected.to_char(b) && c.is_none_or(|c| code_page.from_char(c) == Some(b))
+        })
+    }
+
+    fn code_page_file(code_page: u16) -> Vec<u8> {
+        let code_page_table = CodePage::generate(code_page);
+        let mut file = file_header(code_page, CodePageKind::SingleByte, code_page_table.as_bytes()).to_vec();
+        file.extend_from_slice(code_page_table.as_bytes());
+        file
+    }
+
+    #[quickcheck]
+    fn from_bytes_is_generate(code_page: KnownCodePage) -> bool {
+        let code_page = KNOWN_CODE_PAGES[code_page.0 as usize];
+        let file = code_page_file(code_page);
+        CodePage::from_bytes(&file).unwrap().as_bytes() == CodePage::generate(code_page).as_bytes()
+    }
+
+    #[quickcheck]
+    fn from_bytes_detects_corruption(code_page: KnownCodePage, offset: usize, xor: u8) -> TestResult {
+        let mut file = code_page_file(KNOWN_CODE_PAGES[code_page.0 as usize]);
+        let offset = offset % file.len();
+        if xor == 0 || (6 .. 8).contains(&offset) { return TestResult::discard(); }
+        file[offset] ^= xor;
+        TestResult::from_bool(CodePage::from_bytes(&file).is_err())
+    }
+
+    #[test]
+    fn from_bytes_errors() {
+        let file = code_page_file(866);
+        assert_eq!(CodePage::from_bytes(&file[.. 10]).unwrap_err(), FormatError::TooShort);
+        assert!(matches!(CodePage::from_bytes(&file[.. 500]), Err(FormatError::InvalidLength { len: 640 })));
+        let mut bad = file.clone();
+        bad[0] = b'X';
+        assert_eq!(CodePage::from_bytes(&bad).unwrap_err(), FormatError::InvalidMagic);
+        let mut bad = file.clone();
+        bad[4] = 1;
+        assert_eq!(CodePage::from_bytes(&bad).unwrap_err(), FormatError::UnsupportedVersion { version: 1 });
+        let mut body = CodePage::generate(866).as_bytes().to_vec();
+        body[0] = 0xD8;
+        let mut bad = file_header(866, CodePageKind::SingleByte, &body).to_vec();
+        bad.extend_from_slice(&body);
+        assert_eq!(
+            CodePage::from_bytes(&bad).unwrap_err(),
+            FormatError::Inconsistent { inconsistency: Inconsistency::SurrogateChar { byte: 0x80 } }
+        );
+        let mut body = CodePage::generate(866).as_bytes().to_vec();
+        body.swap(1, 3);
+        let mut bad = file_header(866, CodePageKind::SingleByte, &body).to_vec();
+        bad.extend_from_slice(&body);
+        assert!(matches!(CodePage::from_bytes(&bad), Err(FormatError::Inconsistent { .. })));
+        let dbcs = generate_dbcs(dbcs_lead_ranges(932).unwrap(), dbcs_test_mapping()).unwrap();
+        let mut dbcs_file = file_header(932, CodePageKind::DoubleByte, &dbcs).to_vec();
+        dbcs_file.extend_from_slice(&dbcs);
+        assert_eq!(CodePage::from_bytes(&dbcs_file).unwrap_err(), FormatError::KindMismatch { kind: CodePageKind::DoubleByte });
+        assert_eq!(DbcsCodePage::from_bytes(&dbcs_file).unwrap().as_bytes(), &dbcs[..]);
+    }
+
+    fn corrupted(code_page: u16, corrupt: impl FnOnce(&mut [u8])) -> Vec<Inconsistency> {
+        let mut body = CodePage::generate(code_page).as_bytes().to_vec();
+        corrupt(&mut body);
+        let mut file = file_header(code_page, CodePageKind::SingleByte, &body).to_vec();
+        file.extend_from_slice(&body);
+        let Err(FormatError::Inconsistent { inconsistency }) = CodePage::from_bytes(&file) else { panic!() };
+        let mut bytes = [0; 896];
+        bytes[.. body.len()].copy_from_slice(&body);
+        let inconsistencies = verify_bytes(&bytes);
+        assert_eq!(inconsistencies.first(), Some(&inconsistency));
+        inconsistencies
+    }
+
+    fn verify_bytes(bytes: &[u8; 896]) -> Vec<Inconsistency> {
+        unsafe { CodePage::from_raw(*bytes) }.verify().collect()
+    }
+
+    #[test]
+    fn verify_reports_every_inconsistency() {
+        // 'А' and 'Б' are both mapped from 80h.
+        assert_eq!(corrupted(866, |body| body[3] = 0x10), [
+            Inconsistency::DuplicateMapping { byte: 0x81, other: 0x80 },
+            Inconsistency::MissingBucketEntry { byte: 0x81 },
+            Inconsistency::DanglingBucketEntry { offset: 256 + 2 * hash(0x0411, 0x8398) as usize },
+        ]);
+        assert_eq!(corrupted(866, |body| body[0] = 0xDC), [
+            Inconsistency::SurrogateChar { byte: 0x80 },
+            Inconsistency::DanglingBucketEntry { offset: 256 + 2 * hash(0x0410, 0x8398) as usize },
+        ]);
+        let mut low_half = [0; 896];
+        low_half[.. 640].copy_from_slice(CodePage::generate(866).as_bytes());
+        low_half[700] = 1;
+        assert_eq!(verify_bytes(&low_half), [Inconsistency::LowHalfWithAsciiFlag { hash_param: 0x8398 }]);
+        // The overflow area of 667 holds at least two entries and must stay ordered by char.
+        assert_eq!(corrupted(667, |body| body.swap(512, 513)), [Inconsistency::DanglingBucketEntry { offset: 513 }]);
+    }
+
+    #[quickcheck]
+    fn known_code_pages_are_consistent(code_page: KnownCodePage) -> bool {
+        tables::code_page(KNOWN_CODE_PAGES[code_page.0 as usize]).verify().next().is_none()
+    }
+
+    #[test]
+    fn builtin_is_generate() {
+        for &code_page in KNOWN_CODE_PAGES {
+            if let Some(builtin) = CodePage::builtin(code_page) {
+                assert_eq!(builtin.as_bytes(), CodePage::generate(code_page).as_bytes());
+            }
+        }
+        assert!(CodePage::builtin(866).is_some());
+        assert!(CodePage::builtin(437).is_some());
+        assert!(CodePage::builtin(999).is_none());
+    }
+
+    #[test]
+    fn named_code_pages() {
+        for &code_page in KNOWN_CODE_PAGES {
+            assert_eq!(code_page_by_name(&format!("CP{code_page}")), Some(code_page));
+            if let Some(name) = code_page_name(code_page) {
+                assert_eq!(code_page_by_name(name), Some(code_page));
+            }
+        }
+        assert_eq!(code_page_by_name("ISO_8859-5"), Some(28595));
+        assert_eq!(code_page_by_name("koi8u"), Some(21866));
+        assert_eq!(code_page_by_name("windows-1251"), Some(1251));
+        assert_eq!(code_page_by_name("866"), Some(866));
+        assert_eq!(code_page_by_name("cp"), None);
+        assert_eq!(code_page_by_name("utf-8"), None);
+        assert_eq!(CodePage::generate(code_page_by_name("KOI8-R").unwrap()).from_char('Я'), Some(0xF1));
+        assert_eq!(CodePage::generate(code_page_by_name("MacRoman").unwrap()).to_char(0xA5), Some('•'));
+    }
+
     #[quickcheck]
     fn to_char_is_from_char_inverse(c: char, code_page: KnownCodePage) -> TestResult {
         let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
@@ -532,6 +765,181 @@ mod test {
         }
     }
 
+    #[quickcheck]
+    fn decode_is_to_char(bytes: Vec<u8>, code_page: KnownCodePage) -> bool {
+        let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
+        code_page.decode(&bytes).zip(bytes.iter().copied().enumerate()).all(|(r, (offset, byte))| {
+            match code_page.to_char(byte) {
+                Some(c) => r == Ok(c),
+                None => r == Err(DecodeError { offset, byte }),
+            }
+        })
+    }
+
+    #[quickcheck]
+    fn oem_chars_is_decode(bytes: Vec<u8>, code_page: KnownCodePage) -> bool {
+        let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
+        bytes.iter().copied().oem_chars(&code_page).eq(code_page.decode(&bytes))
+    }
+
+    #[quickcheck]
+    fn encode_into_strict_is_from_char(s: String, code_page: KnownCodePage) -> bool {
+        let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
+        let mut buf = vec![0; s.len()];
+        let res = code_page.encode_into(&s, &mut buf, Unmappable::Strict);
+        if s.chars().any(|c| ('\u{300}' ..= '\u{36F}').contains(&c)) {
+            // Marks may be composed, so only check consistency with the other policies.
+            let mut replaced = vec![0; s.len()];
+            let replaced = code_page.encode_into(&s, &mut replaced, Unmappable::Replace(b'?')).map(|x| replaced[.. x.written].to_vec());
+            return match res {
+                Ok(Encoded { read, written }) => read == s.len() && Ok(buf[.. written].to_vec()) == replaced,
+                Err(EncodeError { index, c, written }) => s[index ..].starts_with(c) && code_page.from_char(c).is_none()
+                    && code_page.encode_into(&s[.. index], &mut buf, Unmappable::Strict) == Ok(Encoded { read: index, written }),
+            };
+        }
+        let expected = s.char_indices().map(|(i, c)| code_page.from_char(c).ok_or((i, c)))
+            .collect::<Result<Vec<_>, _>>();
+        match (res, expected) {
+            (Ok(Encoded { read, written }), Ok(bytes)) => read == s.len() && buf[.. written] == bytes[..],
+            (Err(EncodeError { index, c, .. }), Err((i, u))) => index == i && c == u,
+            _ => false,
+        }
+    }
+
+    #[quickcheck]
+    fn encode_into_never_splits_chars(s: String, len: u8, code_page: KnownCodePage) -> bool {
+        let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
+        let mut buf = vec![0; len as usize];
+        let Encoded { read, written } = code_page.encode_into(&s, &mut buf, Unmappable::Replace(b'?')).unwrap();
+        let mut prefix_buf = vec![0; s.len()];
+        s.is_char_boundary(read)
+            && code_page.encode_into(&s[.. read], &mut prefix_buf, Unmappable::Replace(b'?')) == Ok(Encoded { read, written })
+            && buf[.. written] == prefix_buf[.. written]
+    }
+
+    #[quickcheck]
+    fn can_encode_is_from_char(s: String, code_page: KnownCodePage) -> bool {
+        let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
+        let unmappable: Vec<_> = s.char_indices().filter(|&(_, c)| code_page.from_char(c).is_none()).collect();
+        let coverage = code_page.coverage(&s);
+        code_page.can_encode(&s) == unmappable.first().map_or(Ok(()), |&x| Err(x))
+            && coverage.is_complete() == unmappable.is_empty()
+            && coverage.unmappable_count() == unmappable.len()
+            && coverage.collect::<Vec<_>>() == unmappable
+    }
+
+    #[test]
+    fn encode_into_composes_marks() {
+        let encode = |code_page, s| {
+            let mut buf = [0; 16];
+            let Encoded { read, written } = CodePage::generate(code_page)
+                .encode_into(s, &mut buf, Unmappable::Replace(b'?')).unwrap();
+            assert_eq!(read, s.len());
+            buf[.. written].to_vec()
+        };
+        assert_eq!(encode(850, "e\u{301}te\u{301}"), b"\x82t\x82");
+        assert_eq!(encode(852, "c\u{30C}e\u{301}"), b"\x9F\x82");
+        assert_eq!(encode(866, "\u{44F}\u{301}"), b"\xEF?");
+        assert_eq!(encode(866, "\u{438}\u{306}"), b"\xA9");
+        assert_eq!(encode(437, "\u{301}a\u{301}\u{301}"), b"?\xA0?");
+        assert_eq!(encode(1258, "\u{103}\u{301}"), b"\xE3\xEC");
+        let mut buf = [0; 2];
+        let encoded = CodePage::generate(850).encode_into("ae\u{301}", &mut buf[.. 1], Unmappable::Replace(b'?'));
+        assert_eq!(encoded, Ok(Encoded { read: 1, written: 1 }));
+        let strict = |code_page, s| CodePage::generate(code_page).encode_into(s, &mut [0; 16], Unmappable::Strict);
+        assert_eq!(strict(437, "x\u{301}"), Err(EncodeError { index: 1, c: '\u{301}', written: 1 }));
+        assert_eq!(strict(437, "a\u{30C}"), Err(EncodeError { index: 1, c: '\u{30C}', written: 1 }));
+        assert_eq!(strict(850, "e\u{301}"), Ok(Encoded { read: 3, written: 1 }));
+        let mut buf = [0; 16];
+        let escape = Unmappable::Escape(EscapeStyle::Rust);
+        let Encoded { written, .. } = CodePage::generate(437).encode_into("a\u{30C}", &mut buf, escape).unwrap();
+        assert_eq!(&buf[.. written], b"a\\u{030C}");
+    }
+
+    #[test]
+    fn encode_into_policies() {
+        let code_page = CodePage::generate(866);
+        let mut buf = [0; 32];
+        let s = "Я→x";
+        let encode = |unmappable, buf: &mut [u8]| code_page.encode_into(s, buf, unmappable);
+        assert_eq!(encode(Unmappable::Strict, &mut buf), Err(EncodeError { index: 2, c: '→', written: 1 }));
+        let Encoded { written, .. } = encode(Unmappable::Replace(b'*'), &mut buf).unwrap();
+        assert_eq!(&buf[.. written], b"\x9F*x");
+        let Encoded { written, .. } = encode(Unmappable::Skip, &mut buf).unwrap();
+        assert_eq!(&buf[.. written], b"\x9Fx");
+        let Encoded { written, .. } = encode(Unmappable::Escape(EscapeStyle::Rust), &mut buf).unwrap();
+        assert_eq!(&buf[.. written], b"\x9F\\u{2192}x");
+        let Encoded { written, .. } = encode(Unmappable::Escape(EscapeStyle::Html), &mut buf).unwrap();
+        assert_eq!(&buf[.. written], b"\x9F&#8594;x");
+        let Encoded { written, .. } = encode(Unmappable::Callback(|_, buf| { buf[.. 2].copy_from_slice(b"->"); Some(2) }), &mut buf).unwrap();
+        assert_eq!(&buf[.. written], b"\x9F->x");
+        assert_eq!(encode(Unmappable::Callback(|_, _| None), &mut buf), Err(EncodeError { index: 2, c: '→', written: 1 }));
+        let too_long = Unmappable::Callback(|_, _| Some(MAX_REPLACEMENT_LEN + 1));
+        assert_eq!(encode(too_long, &mut buf), Err(EncodeError { index: 2, c: '→', written: 1 }));
+        assert_eq!(encode(Unmappable::Escape(EscapeStyle::Rust), &mut buf[.. 4]), Ok(Encoded { read: 2, written: 1 }));
+    }
+
+    #[test]
+    fn best_fit_transliterates() {
+        let best_fit = |code_page, c| {
+            let code_page = CodePage::generate(code_page);
+            let mut buf = [0; 16];
+            let len = code_page.best_fit(c, &mut buf)?;
+            Some(code_page.decode(&buf[.. len]).collect::<Result<String, _>>().unwrap())
+        };
+        assert_eq!(best_fit(437, '“').as_deref(), Some("\""));
+        assert_eq!(best_fit(437, '…').as_deref(), Some("..."));
+        assert_eq!(best_fit(866, 'é').as_deref(), Some("e"));
+        assert_eq!(best_fit(866, 'ﬁ').as_deref(), Some("fi"));
+        assert_eq!(best_fit(912, '─').as_deref(), Some("-"));
+        assert_eq!(best_fit(912, '═').as_deref(), Some("="));
+        assert_eq!(best_fit(912, '╔').as_deref(), Some("+"));
+        assert_eq!(best_fit(866, '☃'), None);
+    }
+
+    #[quickcheck]
+    fn best_fit_is_used_only_for_unmappable(s: String, code_page: KnownCodePage) -> bool {
+        let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
+        // Combining marks are composed before falling back, see encode_into_composes_marks.
+        let s = s.chars().filter(|c| !('\u{300}' ..= '\u{36F}').contains(c)).collect::<String>();
+        let mut expected = Vec::new();
+        for c in s.chars() {
+            let mut fallback = [0; 16];
+            if let Some(b) = code_page.from_char(c) {
+                expected.push(b);
+            } else if let Some(len) = code_page.best_fit(c, &mut fallback) {
+                expected.extend_from_slice(&fallback[.. len]);
+            } else {
+                expected.push(b'?');
+            }
+        }
+        let mut buf = vec![0; 16 * s.chars().count()];
+        let Encoded { read, written } = code_page.encode_into(&s, &mut buf, Unmappable::BestFit(b'?')).unwrap();
+        read == s.len() && buf[.. written] == expected[..]
+    }
+
+    #[test]
+    fn display_chars_are_dos_glyphs() {
+        let code_page = CodePage::generate(437);
+        let glyphs = (0x01 ..= 0x1F).chain([0x7F]).map(|b| code_page.to_display_char(b).unwrap()).collect::<String>();
+        assert_eq!(glyphs, "☺☻♥♦♣♠•◘○◙♂♀♪♫☼►◄↕‼¶§▬↨↑↓→←∟↔▲▼⌂");
+        assert_eq!(code_page.to_display_char(0x00), Some('\0'));
+        assert_eq!(code_page.from_display_char('\n'), None);
+        assert_eq!(code_page.from_display_char('◙'), Some(0x0A));
+        assert_eq!(code_page.decode(b"\x01A\x80").display().collect::<Result<String, _>>(), Ok("☺AÇ".into()));
+    }
+
+    #[quickcheck]
+    fn from_display_char_is_to_display_char_inverse(c: u8, code_page: KnownCodePage) -> TestResult {
+        let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
+        if let Some(u) = code_page.to_display_char(c) {
+            let b = code_page.from_display_char(u).unwrap();
+            TestResult::from_bool(code_page.to_display_char(b) == Some(u))
+        } else {
+            TestResult::discard()
+        }
+    }
+
     #[quickcheck]
     fn from_char_is_to_char_inverse(c: u8, code_page: KnownCodePage) -> TestResult {
         let code_page = CodePage::generate(KNOWN_CODE_PAGES[code_page.0 as usize]);
@@ -541,4 +949,59 @@ mod test {
             TestResult::discard()
         }
     }
+
+    fn dbcs_test_mapping() -> Vec<(u16, char)> {
+        let mut mapping = vec![(0x80, '€'), (0xA1, '｡'), (0xDF, 'ﾟ')];
+        for (i, trail) in (0x40 ..= 0xFC).filter(|&x| x != 0x7F).enumerate() {
+            mapping.push((0x8800 | trail, char::from_u32(0x4E00 + i as u32).unwrap()));
+            mapping.push((0xE000 | trail, char::from_u32(0xAC00 + i as u32).unwrap()));
+        }
+        mapping.push((0x8140, '\u{3000}'));
+        mapping
+    }
+
+    #[test]
+    fn dbcs_to_char_is_from_char_inverse() {
+        let code_page = generate_dbcs(dbcs_lead_ranges(932).unwrap(), dbcs_test_mapping()).unwrap();
+        let code_page = DbcsCodePage::new(&code_page).unwrap();
+        for (code, c) in dbcs_test_mapping() {
+            if code >> 8 == 0 {
+                assert_eq!(code_page.to_char(code as u8), Some(c));
+                assert_eq!(code_page.from_char(c), Some(DbcsChar::Single(code as u8)));
+            } else {
+                assert_eq!(code_page.to_char_pair((code >> 8) as u8, code as u8), Some(c));
+                assert_eq!(code_page.from_char(c), Some(DbcsChar::Double((code >> 8) as u8, code as u8)));
+            }
+        }
+        assert_eq!(code_page.to_char(0x88), None);
+        assert_eq!(code_page.to_char(0x81), None);
+        assert_eq!(code_page.to_char_pair(0x88, 0x3F), None);
+        assert_eq!(code_page.from_char('Я'), None);
+    }
+
+    #[test]
+    fn dbcs_decode() {
+        let code_page = generate_dbcs(dbcs_lead_ranges(932).unwrap(), dbcs_test_mapping()).unwrap();
+        let code_page = DbcsCodePage::new(&code_page).unwrap();
+        let decoded: Vec<_> = code_page.decode(b"a\x88\x40\x80\x88\x20b\xE0").collect();
+        assert_eq!(decoded, [
+            Ok('a'),
+            Ok('\u{4E00}'),
+            Ok('€'),
+            Err(DecodeError { offset: 4, byte: 0x88 }),
+            Ok(' '),
+            Ok('b'),
+            Err(DecodeError { offset: 7, byte: 0xE0 }),
+        ]);
+    }
+
+    #[quickcheck]
+    fn dbcs_encode_into_never_splits_chars(s: String, len: u8) -> bool {
+        let code_page = generate_dbcs(dbcs_lead_ranges(932).unwrap(), dbcs_test_mapping()).unwrap();
+        let code_page = DbcsCodePage::new(&code_page).unwrap();
+        let s: String = s.chars().map(|c| if c.is_ascii() { c } else { '\u{4E01}' }).collect();
+        let mut buf = vec![0; len as usize];
+        let Encoded { read, written } = code_page.encode_into(&s, &mut buf, Unmappable::Strict).unwrap();
+        s.is_char_boundary(read) && written == s[.. read].chars().map(|c| c.len_utf8().min(2)).sum::<usize>()
+    }
 }
@@ -0,0 +1,163 @@
+use crate::{NoParamError, find_hash_param};
+use dos_cp::{CodePage, Inconsistency};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MappingError {
+    InvalidLine { line: usize },
+    InvalidByte { line: usize },
+    InvalidChar { line: usize },
+    DuplicateByte { line: usize, byte: u8 },
+    DuplicateChar { line: usize, c: char },
+    UndefinedLowHalfByte { line: usize, byte: u8 },
+}
+
+impl Display for MappingError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            MappingError::InvalidLine { line } => write!(f, "line {line}: invalid mapping"),
+            MappingError::InvalidByte { line } => write!(f, "line {line}: invalid byte"),
+            MappingError::InvalidChar { line } => write!(f, "line {line}: invalid or unsupported char"),
+            MappingError::DuplicateByte { line, byte } => write!(f, "line {line}: byte {byte:02X}h is already mapped"),
+            MappingError::DuplicateChar { line, c } =>
+                write!(f, "line {line}: char U+{:04X} is already mapped", *c as u32),
+            MappingError::UndefinedLowHalfByte { line, byte } =>
+                write!(f, "line {line}: byte {byte:02X}h is in the low half and cannot be undefined"),
+        }
+    }
+}
+
+impl Error for MappingError { }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableError {
+    NoParam { error: NoParamError },
+    Inconsistent { inconsistency: Inconsistency },
+}
+
+impl Display for TableError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableError::NoParam { error } => write!(f, "{error}"),
+            TableError::Inconsistent { inconsistency } => write!(f, "inconsistent table: {inconsistency}"),
+        }
+    }
+}
+
+impl Error for TableError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TableError::NoParam { error } => Some(error),
+            TableError::Inconsistent { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mapping {
+    pub upper_half: [char; 128],
+    pub low_half_overrides: Vec<(u8, char)>,
+}
+
+impl Mapping {
+    /// Parses the Unicode consortium mapping format (`0x80\t0x00C7\t# LATIN CAPITAL LETTER C WITH CEDILLA`).
+    /// Upper half bytes without a char (`0x81\t\t#UNDEFINED`) or without a line are undefined.
+    /// Low half bytes cannot be undefined, they default to ASCII.
+    pub fn parse(text: &str) -> Result<Mapping, MappingError> {
+        let mut entries = Vec::new();
+        let mut mapped = [false; 256];
+        for (line, s) in text.lines().enumerate() {
+            let line = line + 1;
+            let s = s.split_once('#').map_or(s, |(s, _)| s);
+            let mut fields = s.split_whitespace();
+            let Some(byte) = fields.next() else { continue; };
+            let c = fields.next();
+            if fields.next().is_some() { return Err(MappingError::InvalidLine { line }); }
+            let byte = parse_hex(byte).and_then(|x| u8::try_from(x).ok()).ok_or(MappingError::InvalidByte { line })?;
+            if mapped[byte as usize] { return Err(MappingError::DuplicateByte { line, byte }); }
+            mapped[byte as usize] = true;
+            let Some(c) = c else {
+                if byte >> 7 == 0 { return Err(MappingError::UndefinedLowHalfByte { line, byte }); }
+                continue;
+            };
+            let c = parse_hex(c)
+                .filter(|&c| c >> 16 == 0)
+                .and_then(char::from_u32)
+                .ok_or(MappingError::InvalidChar { line })?;
+            if byte >> 7 == 0 && c == byte as char { continue; }
+            if byte == 0 || byte >> 7 != 0 && c == '?' { return Err(MappingError::InvalidChar { line }); }
+            entries.push((line, byte, c));
+        }
+        let mut chars: [Option<char>; 256] = [None; 256];
+        for byte in 0 .. 0x80u8 {
+            chars[byte as usize] = Some(byte as char);
+        }
+        for &(_, byte, c) in &entries {
+            chars[byte as usize] = Some(c);
+        }
+        for &(line, byte, c) in &entries {
+            if (0 .. 256).any(|other| other != byte as usize && chars[other] == Some(c)) {
+                return Err(MappingError::DuplicateChar { line, c });
+            }
+        }
+        let mut upper_half = ['?'; 128];
+        let mut low_half_overrides = Vec::new();
+        for (_, byte, c) in entries {
+            if byte >> 7 != 0 {
+                upper_half[(byte & 0x7F) as usize] = c;
+            } else {
+                low_half_overrides.push((byte, c));
+            }
+        }
+        Ok(Mapping { upper_half, low_half_overrides })
+    }
+
+    pub fn generate(&self) -> Result<CodePage, TableError> {
+        if let Some(inconsistency) = duplicate_mapping(&self.upper_half) {
+            return Err(TableError::Inconsistent { inconsistency });
+        }
+        let hash_param = find_hash_param(&self.upper_half).map_err(|error| TableError::NoParam { error })?;
+        let code_page = CodePage::with_hash_param(&self.upper_half, hash_param)
+            .with_low_half_overrides(&self.low_half_overrides);
+        if let Some(inconsistency) = code_page.verify().next() {
+            return Err(TableError::Inconsistent { inconsistency });
+        }
+        Ok(code_page)
+    }
+}
+
+/// `CodePage::with_hash_param` panics on duplicate chars, so they are looked for before building the table.
+pub(crate) fn duplicate_mapping(upper_half: &[char; 128]) -> Option<Inconsistency> {
+    for (i, &c) in upper_half.iter().enumerate().filter(|&(_, &c)| c != '?') {
+        if let Some(other) = upper_half[.. i].iter().position(|&x| x == c) {
+            return Some(Inconsistency::DuplicateMapping { byte: 0x80 | i as u8, other: 0x80 | other as u8 });
+        }
+    }
+    None
+}
+
+/// Parses a double-byte mapping in the Unicode consortium format (`0x8140\t0x3000\t# IDEOGRAPHIC SPACE`),
+/// as in `CP932.TXT`, `CP936.TXT`, `CP949.TXT` and `CP950.TXT`.
+/// Codes without a char (`0x81\t\t#DBCS LEAD BYTE`) are skipped, the rest is checked by [`generate_dbcs`](crate::generate_dbcs).
+pub fn parse_dbcs_mapping(text: &str) -> Result<Vec<(u16, char)>, MappingError> {
+    let mut res = Vec::new();
+    for (line, s) in text.lines().enumerate() {
+        let line = line + 1;
+        let s = s.split_once('#').map_or(s, |(s, _)| s);
+        let mut fields = s.split_whitespace();
+        let Some(code) = fields.next() else { continue; };
+        let c = fields.next();
+        if fields.next().is_some() { return Err(MappingError::InvalidLine { line }); }
+        let code = parse_hex(code).and_then(|x| u16::try_from(x).ok()).ok_or(MappingError::InvalidByte { line })?;
+        let Some(c) = c else { continue; };
+        let c = parse_hex(c).and_then(char::from_u32).ok_or(MappingError::InvalidChar { line })?;
+        res.push((code, c));
+    }
+    Ok(res)
+}
+
+fn parse_hex(s: &str) -> Option<u32> {
+    let s = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))?;
+    u32::from_str_radix(s, 16).ok()
+}
@@ -0,0 +1,655 @@
+pub(crate) fn best_fit(c: char, buf: &mut [u8], encode_char: impl Fn(char, &mut [u8]) -> Option<usize>) -> Option<usize> {
+    let i = BEST_FIT.binary_search_by_key(&c, |&(x, _)| x).ok()?;
+    'candidates: for candidate in BEST_FIT[i].1.split('\0') {
+        let mut len = 0;
+        for c in candidate.chars() {
+            let Some(char_len) = encode_char(c, &mut buf[len ..]) else { continue 'candidates; };
+            len += char_len;
+        }
+        return Some(len);
+    }
+    None
+}
+
+// Sorted by char, candidates are separated by '\0' in the order of preference.
+static BEST_FIT: &[(char, &str)] = &[
+    ('\u{0025}', "\u{066A}"),
+    ('\u{00A0}', " "),
+    ('\u{00A1}', "!"),
+    ('\u{00A2}', "c"),
+    ('\u{00A3}', "L"),
+    ('\u{00A4}', "*"),
+    ('\u{00A5}', "Y"),
+    ('\u{00A6}', "|"),
+    ('\u{00A7}', "S"),
+    ('\u{00A8}', "\""),
+    ('\u{00A9}', "(C)"),
+    ('\u{00AA}', "a"),
+    ('\u{00AB}', "<<"),
+    ('\u{00AC}', "-"),
+    ('\u{00AD}', "-"),
+    ('\u{00AE}', "(R)"),
+    ('\u{00AF}', "-"),
+    ('\u{00B0}', "o"),
+    ('\u{00B1}', "+-"),
+    ('\u{00B2}', "2"),
+    ('\u{00B3}', "3"),
+    ('\u{00B4}', "'"),
+    ('\u{00B5}', "μ\0u"),
+    ('\u{00B6}', "P"),
+    ('\u{00B7}', "∙\0."),
+    ('\u{00B8}', ","),
+    ('\u{00B9}', "1"),
+    ('\u{00BA}', "o"),
+    ('\u{00BB}', ">>"),
+    ('\u{00BC}', "1/4"),
+    ('\u{00BD}', "1/2"),
+    ('\u{00BE}', "3/4"),
+    ('\u{00BF}', "?"),
+    ('\u{00C0}', "A"),
+    ('\u{00C1}', "A"),
+    ('\u{00C2}', "A"),
+    ('\u{00C3}', "A"),
+    ('\u{00C4}', "A"),
+    ('\u{00C5}', "A"),
+    ('\u{00C6}', "AE"),
+    ('\u{00C7}', "C"),
+    ('\u{00C8}', "E"),
+    ('\u{00C9}', "E"),
+    ('\u{00CA}', "E"),
+    ('\u{00CB}', "E"),
+    ('\u{00CC}', "I"),
+    ('\u{00CD}', "I"),
+    ('\u{00CE}', "I"),
+    ('\u{00CF}', "I"),
+    ('\u{00D0}', "Đ\0D"),
+    ('\u{00D1}', "N"),
+    ('\u{00D2}', "O"),
+    ('\u{00D3}', "O"),
+    ('\u{00D4}', "O"),
+    ('\u{00D5}', "O"),
+    ('\u{00D6}', "O"),
+    ('\u{00D7}', "x"),
+    ('\u{00D8}', "O"),
+    ('\u{00D9}', "U"),
+    ('\u{00DA}', "U"),
+    ('\u{00DB}', "U"),
+    ('\u{00DC}', "U"),
+    ('\u{00DD}', "Y"),
+    ('\u{00DE}', "Th"),
+    ('\u{00DF}', "ss"),
+    ('\u{00E0}', "a"),
+    ('\u{00E1}', "a"),
+    ('\u{00E2}', "a"),
+    ('\u{00E3}', "a"),
+    ('\u{00E4}', "a"),
+    ('\u{00E5}', "a"),
+    ('\u{00E6}', "ae"),
+    ('\u{00E7}', "c"),
+    ('\u{00E8}', "e"),
+    ('\u{00E9}', "e"),
+    ('\u{00EA}', "e"),
+    ('\u{00EB}', "e"),
+    ('\u{00EC}', "i"),
+    ('\u{00ED}', "i"),
+    ('\u{00EE}', "i"),
+    ('\u{00EF}', "i"),
+    ('\u{00F0}', "d"),
+    ('\u{00F1}', "n"),
+    ('\u{00F2}', "o"),
+    ('\u{00F3}', "o"),
+    ('\u{00F4}', "o"),
+    ('\u{00F5}', "o"),
+    ('\u{00F6}', "o"),
+    ('\u{00F7}', "/"),
+    ('\u{00F8}', "o"),
+    ('\u{00F9}', "u"),
+    ('\u{00FA}', "u"),
+    ('\u{00FB}', "u"),
+    ('\u{00FC}', "u"),
+    ('\u{00FD}', "y"),
+    ('\u{00FE}', "th"),
+    ('\u{00FF}', "y"),
+    ('\u{0100}', "A"),
+    ('\u{0101}', "a"),
+    ('\u{0102}', "A"),
+    ('\u{0103}', "a"),
+    ('\u{0104}', "A"),
+    ('\u{0105}', "a"),
+    ('\u{0106}', "C"),
+    ('\u{0107}', "c"),
+    ('\u{0108}', "C"),
+    ('\u{0109}', "c"),
+    ('\u{010A}', "C"),
+    ('\u{010B}', "c"),
+    ('\u{010C}', "C"),
+    ('\u{010D}', "c"),
+    ('\u{010E}', "D"),
+    ('\u{010F}', "d"),
+    ('\u{0110}', "Ð\0D"),
+    ('\u{0111}', "d"),
+    ('\u{0112}', "E"),
+    ('\u{0113}', "e"),
+    ('\u{0114}', "E"),
+    ('\u{0115}', "e"),
+    ('\u{0116}', "E"),
+    ('\u{0117}', "e"),
+    ('\u{0118}', "E"),
+    ('\u{0119}', "e"),
+    ('\u{011A}', "E"),
+    ('\u{011B}', "e"),
+    ('\u{011C}', "G"),
+    ('\u{011D}', "g"),
+    ('\u{011E}', "G"),
+    ('\u{011F}', "g"),
+    ('\u{0120}', "G"),
+    ('\u{0121}', "g"),
+    ('\u{0122}', "G"),
+    ('\u{0123}', "g"),
+    ('\u{0124}', "H"),
+    ('\u{0125}', "h"),
+    ('\u{0126}', "H"),
+    ('\u{0127}', "h"),
+    ('\u{0128}', "I"),
+    ('\u{0129}', "i"),
+    ('\u{012A}', "I"),
+    ('\u{012B}', "i"),
+    ('\u{012C}', "I"),
+    ('\u{012D}', "i"),
+    ('\u{012E}', "I"),
+    ('\u{012F}', "i"),
+    ('\u{0130}', "I"),
+    ('\u{0131}', "i"),
+    ('\u{0132}', "IJ"),
+    ('\u{0133}', "ij"),
+    ('\u{0134}', "J"),
+    ('\u{0135}', "j"),
+    ('\u{0136}', "K"),
+    ('\u{0137}', "k"),
+    ('\u{0138}', "q"),
+    ('\u{0139}', "L"),
+    ('\u{013A}', "l"),
+    ('\u{013B}', "L"),
+    ('\u{013C}', "l"),
+    ('\u{013D}', "L"),
+    ('\u{013E}', "l"),
+    ('\u{013F}', "L"),
+    ('\u{0140}', "l"),
+    ('\u{0141}', "L"),
+    ('\u{0142}', "l"),
+    ('\u{0143}', "N"),
+    ('\u{0144}', "n"),
+    ('\u{0145}', "N"),
+    ('\u{0146}', "n"),
+    ('\u{0147}', "N"),
+    ('\u{0148}', "n"),
+    ('\u{0149}', "'n"),
+    ('\u{014A}', "N"),
+    ('\u{014B}', "n"),
+    ('\u{014C}', "O"),
+    ('\u{014D}', "o"),
+    ('\u{014E}', "O"),
+    ('\u{014F}', "o"),
+    ('\u{0150}', "O"),
+    ('\u{0151}', "o"),
+    ('\u{0152}', "OE"),
+    ('\u{0153}', "oe"),
+    ('\u{0154}', "R"),
+    ('\u{0155}', "r"),
+    ('\u{0156}', "R"),
+    ('\u{0157}', "r"),
+    ('\u{0158}', "R"),
+    ('\u{0159}', "r"),
+    ('\u{015A}', "S"),
+    ('\u{015B}', "s"),
+    ('\u{015C}', "S"),
+    ('\u{015D}', "s"),
+    ('\u{015E}', "S"),
+    ('\u{015F}', "s"),
+    ('\u{0160}', "S"),
+    ('\u{0161}', "s"),
+    ('\u{0162}', "T"),
+    ('\u{0163}', "t"),
+    ('\u{0164}', "T"),
+    ('\u{0165}', "t"),
+    ('\u{0166}', "T"),
+    ('\u{0167}', "t"),
+    ('\u{0168}', "U"),
+    ('\u{0169}', "u"),
+    ('\u{016A}', "U"),
+    ('\u{016B}', "u"),
+    ('\u{016C}', "U"),
+    ('\u{016D}', "u"),
+    ('\u{016E}', "U"),
+    ('\u{016F}', "u"),
+    ('\u{0170}', "U"),
+    ('\u{0171}', "u"),
+    ('\u{0172}', "U"),
+    ('\u{0173}', "u"),
+    ('\u{0174}', "W"),
+    ('\u{0175}', "w"),
+    ('\u{0176}', "Y"),
+    ('\u{0177}', "y"),
+    ('\u{0178}', "Y"),
+    ('\u{0179}', "Z"),
+    ('\u{017A}', "z"),
+    ('\u{017B}', "Z"),
+    ('\u{017C}', "z"),
+    ('\u{017D}', "Z"),
+    ('\u{017E}', "z"),
+    ('\u{017F}', "s"),
+    ('\u{018F}', "E"),
+    ('\u{0192}', "f"),
+    ('\u{01CD}', "A"),
+    ('\u{01CE}', "a"),
+    ('\u{01CF}', "I"),
+    ('\u{01D0}', "i"),
+    ('\u{01D1}', "O"),
+    ('\u{01D2}', "o"),
+    ('\u{01D3}', "U"),
+    ('\u{01D4}', "u"),
+    ('\u{01D5}', "Ü\0U"),
+    ('\u{01D6}', "ü\0u"),
+    ('\u{01D7}', "Ü\0U"),
+    ('\u{01D8}', "ü\0u"),
+    ('\u{01D9}', "Ü\0U"),
+    ('\u{01DA}', "ü\0u"),
+    ('\u{01DB}', "Ü\0U"),
+    ('\u{01DC}', "ü\0u"),
+    ('\u{0218}', "S"),
+    ('\u{0219}', "s"),
+    ('\u{021A}', "T"),
+    ('\u{021B}', "t"),
+    ('\u{0259}', "e"),
+    ('\u{0386}', "Α"),
+    ('\u{0387}', "·"),
+    ('\u{0388}', "Ε"),
+    ('\u{0389}', "Η"),
+    ('\u{038A}', "Ι"),
+    ('\u{038C}', "Ο"),
+    ('\u{038E}', "Υ"),
+    ('\u{038F}', "Ω"),
+    ('\u{0390}', "ϊ\0ι"),
+    ('\u{03A9}', "Ω"),
+    ('\u{03AA}', "Ι"),
+    ('\u{03AB}', "Υ"),
+    ('\u{03AC}', "α"),
+    ('\u{03AD}', "ε"),
+    ('\u{03AE}', "η"),
+    ('\u{03AF}', "ι"),
+    ('\u{03B0}', "ϋ\0υ"),
+    ('\u{03CA}', "ι"),
+    ('\u{03CB}', "υ"),
+    ('\u{03CC}', "ο"),
+    ('\u{03CD}', "υ"),
+    ('\u{03CE}', "ω"),
+    ('\u{0401}', "Е"),
+    ('\u{0404}', "Е"),
+    ('\u{0405}', "S"),
+    ('\u{0406}', "I"),
+    ('\u{0407}', "І\0I"),
+    ('\u{0408}', "J"),
+    ('\u{040E}', "У"),
+    ('\u{0451}', "е"),
+    ('\u{0454}', "е"),
+    ('\u{0455}', "s"),
+    ('\u{0456}', "i"),
+    ('\u{0457}', "і\0i"),
+    ('\u{0458}', "j"),
+    ('\u{045E}', "у"),
+    ('\u{0490}', "Г"),
+    ('\u{0491}', "г"),
+    ('\u{2000}', " "),
+    ('\u{2001}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2004}', " "),
+    ('\u{2005}', " "),
+    ('\u{2006}', " "),
+    ('\u{2007}', " "),
+    ('\u{2008}', " "),
+    ('\u{2009}', " "),
+    ('\u{200A}', " "),
+    ('\u{200B}', ""),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "–\0-"),
+    ('\u{2015}', "—\0-"),
+    ('\u{2016}', "||"),
+    ('\u{2017}', "_"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', ","),
+    ('\u{201B}', "'"),
+    ('\u{201C}', "\""),
+    ('\u{201D}', "\""),
+    ('\u{201E}', "\""),
+    ('\u{201F}', "\""),
+    ('\u{2020}', "+"),
+    ('\u{2021}', "+"),
+    ('\u{2022}', "∙\0·\0*"),
+    ('\u{2023}', ">"),
+    ('\u{2024}', "."),
+    ('\u{2025}', ".."),
+    ('\u{2026}', "..."),
+    ('\u{2027}', "-"),
+    ('\u{202F}', " "),
+    ('\u{2030}', "%o"),
+    ('\u{2032}', "'"),
+    ('\u{2033}', "\""),
+    ('\u{2034}', "'''"),
+    ('\u{2039}', "<"),
+    ('\u{203A}', ">"),
+    ('\u{203C}', "!!"),
+    ('\u{2044}', "/"),
+    ('\u{205F}', " "),
+    ('\u{2070}', "0"),
+    ('\u{207F}', "n"),
+    ('\u{20A7}', "Pts"),
+    ('\u{20AC}', "EUR"),
+    ('\u{2116}', "No"),
+    ('\u{2122}', "(TM)"),
+    ('\u{212E}', "e"),
+    ('\u{2190}', "<-"),
+    ('\u{2191}', "^"),
+    ('\u{2192}', "->"),
+    ('\u{2193}', "v"),
+    ('\u{2194}', "<->"),
+    ('\u{21D0}', "<="),
+    ('\u{21D2}', "=>"),
+    ('\u{21D4}', "<=>"),
+    ('\u{2212}', "-"),
+    ('\u{2215}', "/"),
+    ('\u{2216}', "\\"),
+    ('\u{2217}', "*"),
+    ('\u{2219}', "·\0."),
+    ('\u{221A}', "V"),
+    ('\u{221E}', "oo"),
+    ('\u{2223}', "|"),
+    ('\u{2248}', "~"),
+    ('\u{2260}', "!="),
+    ('\u{2261}', "="),
+    ('\u{2264}', "<="),
+    ('\u{2265}', ">="),
+    ('\u{2310}', "-"),
+    ('\u{2500}', "-"),
+    ('\u{2501}', "─\0-"),
+    ('\u{2502}', "|"),
+    ('\u{2503}', "│\0|"),
+    ('\u{2504}', "─\0-"),
+    ('\u{2505}', "─\0-"),
+    ('\u{2506}', "│\0|"),
+    ('\u{2507}', "│\0|"),
+    ('\u{2508}', "─\0-"),
+    ('\u{2509}', "─\0-"),
+    ('\u{250A}', "│\0|"),
+    ('\u{250B}', "│\0|"),
+    ('\u{250C}', "+"),
+    ('\u{250D}', "+"),
+    ('\u{250E}', "+"),
+    ('\u{250F}', "┌\0+"),
+    ('\u{2510}', "+"),
+    ('\u{2511}', "+"),
+    ('\u{2512}', "+"),
+    ('\u{2513}', "┐\0+"),
+    ('\u{2514}', "+"),
+    ('\u{2515}', "+"),
+    ('\u{2516}', "+"),
+    ('\u{2517}', "└\0+"),
+    ('\u{2518}', "+"),
+    ('\u{2519}', "+"),
+    ('\u{251A}', "+"),
+    ('\u{251B}', "┘\0+"),
+    ('\u{251C}', "+"),
+    ('\u{251D}', "+"),
+    ('\u{251E}', "+"),
+    ('\u{251F}', "+"),
+    ('\u{2520}', "+"),
+    ('\u{2521}', "+"),
+    ('\u{2522}', "+"),
+    ('\u{2523}', "├\0+"),
+    ('\u{2524}', "+"),
+    ('\u{2525}', "+"),
+    ('\u{2526}', "+"),
+    ('\u{2527}', "+"),
+    ('\u{2528}', "+"),
+    ('\u{2529}', "+"),
+    ('\u{252A}', "+"),
+    ('\u{252B}', "┤\0+"),
+    ('\u{252C}', "+"),
+    ('\u{252D}', "+"),
+    ('\u{252E}', "+"),
+    ('\u{252F}', "+"),
+    ('\u{2530}', "+"),
+    ('\u{2531}', "+"),
+    ('\u{2532}', "+"),
+    ('\u{2533}', "┬\0+"),
+    ('\u{2534}', "+"),
+    ('\u{2535}', "+"),
+    ('\u{2536}', "+"),
+    ('\u{2537}', "+"),
+    ('\u{2538}', "+"),
+    ('\u{2539}', "+"),
+    ('\u{253A}', "+"),
+    ('\u{253B}', "┴\0+"),
+    ('\u{253C}', "+"),
+    ('\u{253D}', "+"),
+    ('\u{253E}', "+"),
+    ('\u{253F}', "+"),
+    ('\u{2540}', "+"),
+    ('\u{2541}', "+"),
+    ('\u{2542}', "+"),
+    ('\u{2543}', "+"),
+    ('\u{2544}', "+"),
+    ('\u{2545}', "+"),
+    ('\u{2546}', "+"),
+    ('\u{2547}', "+"),
+    ('\u{2548}', "+"),
+    ('\u{2549}', "+"),
+    ('\u{254A}', "+"),
+    ('\u{254B}', "┼\0+"),
+    ('\u{254C}', "-"),
+    ('\u{254D}', "-"),
+    ('\u{254E}', "|"),
+    ('\u{254F}', "|"),
+    ('\u{2550}', "─\0="),
+    ('\u{2551}', "│\0|"),
+    ('\u{2552}', "+"),
+    ('\u{2553}', "+"),
+    ('\u{2554}', "┌\0+"),
+    ('\u{2555}', "+"),
+    ('\u{2556}', "+"),
+    ('\u{2557}', "┐\0+"),
+    ('\u{2558}', "+"),
+    ('\u{2559}', "+"),
+    ('\u{255A}', "└\0+"),
+    ('\u{255B}', "+"),
+    ('\u{255C}', "+"),
+    ('\u{255D}', "┘\0+"),
+    ('\u{255E}', "+"),
+    ('\u{255F}', "+"),
+    ('\u{2560}', "├\0+"),
+    ('\u{2561}', "+"),
+    ('\u{2562}', "+"),
+    ('\u{2563}', "┤\0+"),
+    ('\u{2564}', "+"),
+    ('\u{2565}', "+"),
+    ('\u{2566}', "┬\0+"),
+    ('\u{2567}', "+"),
+    ('\u{2568}', "+"),
+    ('\u{2569}', "┴\0+"),
+    ('\u{256A}', "+"),
+    ('\u{256B}', "+"),
+    ('\u{256C}', "┼\0+"),
+    ('\u{256D}', "┌\0+"),
+    ('\u{256E}', "┐\0+"),
+    ('\u{256F}', "┘\0+"),
+    ('\u{2570}', "└\0+"),
+    ('\u{2571}', "/"),
+    ('\u{2572}', "\\"),
+    ('\u{2573}', "X"),
+    ('\u{2574}', "-"),
+    ('\u{2575}', "|"),
+    ('\u{2576}', "-"),
+    ('\u{2577}', "|"),
+    ('\u{2578}', "╴\0-"),
+    ('\u{2579}', "╵\0|"),
+    ('\u{257A}', "╶\0-"),
+    ('\u{257B}', "╷\0|"),
+    ('\u{257C}', "+"),
+    ('\u{257D}', "+"),
+    ('\u{257E}', "+"),
+    ('\u{257F}', "+"),
+    ('\u{2580}', "#"),
+    ('\u{2581}', "#"),
+    ('\u{2582}', "#"),
+    ('\u{2583}', "#"),
+    ('\u{2584}', "#"),
+    ('\u{2585}', "#"),
+    ('\u{2586}', "█"),
+    ('\u{2587}', "█"),
+    ('\u{2588}', "#"),
+    ('\u{2589}', "█"),
+    ('\u{258A}', "█"),
+    ('\u{258B}', "#"),
+    ('\u{258C}', "#"),
+    ('\u{258D}', "#"),
+    ('\u{258E}', "#"),
+    ('\u{258F}', "#"),
+    ('\u{2590}', "#"),
+    ('\u{2591}', "."),
+    ('\u{2592}', "░\0:"),
+    ('\u{2593}', "▒\0#"),
+    ('\u{2594}', "#"),
+    ('\u{2595}', "#"),
+    ('\u{2596}', "#"),
+    ('\u{2597}', "#"),
+    ('\u{2598}', "#"),
+    ('\u{2599}', "#"),
+    ('\u{259A}', "#"),
+    ('\u{259B}', "#"),
+    ('\u{259C}', "#"),
+    ('\u{259D}', "#"),
+    ('\u{259E}', "#"),
+    ('\u{259F}', "#"),
+    ('\u{25A0}', "#"),
+    ('\u{25A1}', "[]"),
+    ('\u{25AA}', "■\0#"),
+    ('\u{25AB}', "□"),
+    ('\u{25CB}', "o"),
+    ('\u{25CF}', "*"),
+    ('\u{25E6}', "o"),
+    ('\u{2660}', "*"),
+    ('\u{2663}', "*"),
+    ('\u{2665}', "*"),
+    ('\u{2666}', "*"),
+    ('\u{2713}', "v"),
+    ('\u{2714}', "v"),
+    ('\u{2717}', "x"),
+    ('\u{2718}', "x"),
+    ('\u{3000}', " "),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB05}', "st"),
+    ('\u{FB06}', "st"),
+    ('\u{FEFF}', ""),
+    ('\u{FF01}', "!"),
+    ('\u{FF02}', "\""),
+    ('\u{FF03}', "#"),
+    ('\u{FF04}', "$"),
+    ('\u{FF05}', "%"),
+    ('\u{FF06}', "&"),
+    ('\u{FF07}', "'"),
+    ('\u{FF08}', "("),
+    ('\u{FF09}', ")"),
+    ('\u{FF0A}', "*"),
+    ('\u{FF0B}', "+"),
+    ('\u{FF0C}', ","),
+    ('\u{FF0D}', "-"),
+    ('\u{FF0E}', "."),
+    ('\u{FF0F}', "/"),
+    ('\u{FF10}', "0"),
+    ('\u{FF11}', "1"),
+    ('\u{FF12}', "2"),
+    ('\u{FF13}', "3"),
+    ('\u{FF14}', "4"),
+    ('\u{FF15}', "5"),
+    ('\u{FF16}', "6"),
+    ('\u{FF17}', "7"),
+    ('\u{FF18}', "8"),
+    ('\u{FF19}', "9"),
+    ('\u{FF1A}', ":"),
+    ('\u{FF1B}', ";"),
+    ('\u{FF1C}', "<"),
+    ('\u{FF1D}', "="),
+    ('\u{FF1E}', ">"),
+    ('\u{FF1F}', "?"),
+    ('\u{FF20}', "@"),
+    ('\u{FF21}', "A"),
+    ('\u{FF22}', "B"),
+    ('\u{FF23}', "C"),
+    ('\u{FF24}', "D"),
+    ('\u{FF25}', "E"),
+    ('\u{FF26}', "F"),
+    ('\u{FF27}', "G"),
+    ('\u{FF28}', "H"),
+    ('\u{FF29}', "I"),
+    ('\u{FF2A}', "J"),
+    ('\u{FF2B}', "K"),
+    ('\u{FF2C}', "L"),
+    ('\u{FF2D}', "M"),
+    ('\u{FF2E}', "N"),
+    ('\u{FF2F}', "O"),
+    ('\u{FF30}', "P"),
+    ('\u{FF31}', "Q"),
+    ('\u{FF32}', "R"),
+    ('\u{FF33}', "S"),
+    ('\u{FF34}', "T"),
+    ('\u{FF35}', "U"),
+    ('\u{FF36}', "V"),
+    ('\u{FF37}', "W"),
+    ('\u{FF38}', "X"),
+    ('\u{FF39}', "Y"),
+    ('\u{FF3A}', "Z"),
+    ('\u{FF3B}', "["),
+    ('\u{FF3C}', "\\"),
+    ('\u{FF3D}', "]"),
+    ('\u{FF3E}', "^"),
+    ('\u{FF3F}', "_"),
+    ('\u{FF40}', "`"),
+    ('\u{FF41}', "a"),
+    ('\u{FF42}', "b"),
+    ('\u{FF43}', "c"),
+    ('\u{FF44}', "d"),
+    ('\u{FF45}', "e"),
+    ('\u{FF46}', "f"),
+    ('\u{FF47}', "g"),
+    ('\u{FF48}', "h"),
+    ('\u{FF49}', "i"),
+    ('\u{FF4A}', "j"),
+    ('\u{FF4B}', "k"),
+    ('\u{FF4C}', "l"),
+    ('\u{FF4D}', "m"),
+    ('\u{FF4E}', "n"),
+    ('\u{FF4F}', "o"),
+    ('\u{FF50}', "p"),
+    ('\u{FF51}', "q"),
+    ('\u{FF52}', "r"),
+    ('\u{FF53}', "s"),
+    ('\u{FF54}', "t"),
+    ('\u{FF55}', "u"),
+    ('\u{FF56}', "v"),
+    ('\u{FF57}', "w"),
+    ('\u{FF58}', "x"),
+    ('\u{FF59}', "y"),
+    ('\u{FF5A}', "z"),
+    ('\u{FF5B}', "{"),
+    ('\u{FF5C}', "|"),
+    ('\u{FF5D}', "}"),
+    ('\u{FF5E}', "~"),
+];
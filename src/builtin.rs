@@ -0,0 +1,293 @@
+use crate::CodePage;
+
+#[allow(clippy::match_single_binding)]
+pub(crate) fn builtin(code_page: u16) -> Option<&'static CodePage> {
+    match code_page {
+        #[cfg(feature="cp437")]
+        437 => Some(&CP437),
+        #[cfg(feature="cp667")]
+        667 => Some(&CP667),
+        #[cfg(feature="cp720")]
+        720 => Some(&CP720),
+        #[cfg(feature="cp737")]
+        737 => Some(&CP737),
+        #[cfg(feature="cp775")]
+        775 => Some(&CP775),
+        #[cfg(feature="cp790")]
+        790 => Some(&CP790),
+        #[cfg(feature="cp808")]
+        808 => Some(&CP808),
+        #[cfg(feature="cp850")]
+        850 => Some(&CP850),
+        #[cfg(feature="cp852")]
+        852 => Some(&CP852),
+        #[cfg(feature="cp855")]
+        855 => Some(&CP855),
+        #[cfg(feature="cp856")]
+        856 => Some(&CP856),
+        #[cfg(feature="cp857")]
+        857 => Some(&CP857),
+        #[cfg(feature="cp858")]
+        858 => Some(&CP858),
+        #[cfg(feature="cp860")]
+        860 => Some(&CP860),
+        #[cfg(feature="cp861")]
+        861 => Some(&CP861),
+        #[cfg(feature="cp862")]
+        862 => Some(&CP862),
+        #[cfg(feature="cp863")]
+        863 => Some(&CP863),
+        #[cfg(feature="cp864")]
+        864 => Some(&CP864),
+        #[cfg(feature="cp865")]
+        865 => Some(&CP865),
+        #[cfg(feature="cp866")]
+        866 => Some(&CP866),
+        #[cfg(feature="cp867")]
+        867 => Some(&CP867),
+        #[cfg(feature="cp869")]
+        869 => Some(&CP869),
+        #[cfg(feature="cp874")]
+        874 => Some(&CP874),
+        #[cfg(feature="cp895")]
+        895 => Some(&CP895),
+        #[cfg(feature="cp912")]
+        912 => Some(&CP912),
+        #[cfg(feature="cp915")]
+        915 => Some(&CP915),
+        #[cfg(feature="cp1125")]
+        1125 => Some(&CP1125),
+        #[cfg(feature="cp1131")]
+        1131 => Some(&CP1131),
+        #[cfg(feature="cp3021")]
+        3021 => Some(&CP3021),
+        #[cfg(feature="cp1250")]
+        1250 => Some(&CP1250),
+        #[cfg(feature="cp1251")]
+        1251 => Some(&CP1251),
+        #[cfg(feature="cp1252")]
+        1252 => Some(&CP1252),
+        #[cfg(feature="cp1253")]
+        1253 => Some(&CP1253),
+        #[cfg(feature="cp1254")]
+        1254 => Some(&CP1254),
+        #[cfg(feature="cp1255")]
+        1255 => Some(&CP1255),
+        #[cfg(feature="cp1256")]
+        1256 => Some(&CP1256),
+        #[cfg(feature="cp1257")]
+        1257 => Some(&CP1257),
+        #[cfg(feature="cp1258")]
+        1258 => Some(&CP1258),
+        #[cfg(feature="cp10000")]
+        10000 => Some(&CP10000),
+        #[cfg(feature="cp10007")]
+        10007 => Some(&CP10007),
+        #[cfg(feature="cp20866")]
+        20866 => Some(&CP20866),
+        #[cfg(feature="cp21866")]
+        21866 => Some(&CP21866),
+        #[cfg(feature="cp28591")]
+        28591 => Some(&CP28591),
+        #[cfg(feature="cp28592")]
+        28592 => Some(&CP28592),
+        #[cfg(feature="cp28593")]
+        28593 => Some(&CP28593),
+        #[cfg(feature="cp28594")]
+        28594 => Some(&CP28594),
+        #[cfg(feature="cp28595")]
+        28595 => Some(&CP28595),
+        #[cfg(feature="cp28596")]
+        28596 => Some(&CP28596),
+        #[cfg(feature="cp28597")]
+        28597 => Some(&CP28597),
+        #[cfg(feature="cp28598")]
+        28598 => Some(&CP28598),
+        #[cfg(feature="cp28599")]
+        28599 => Some(&CP28599),
+        #[cfg(feature="cp28600")]
+        28600 => Some(&CP28600),
+        #[cfg(feature="cp28601")]
+        28601 => Some(&CP28601),
+        #[cfg(feature="cp28603")]
+        28603 => Some(&CP28603),
+        #[cfg(feature="cp28604")]
+        28604 => Some(&CP28604),
+        #[cfg(feature="cp28605")]
+        28605 => Some(&CP28605),
+        #[cfg(feature="cp28606")]
+        28606 => Some(&CP28606),
+        _ => None,
+    }
+}
+
+#[cfg(feature="cp437")]
+static CP437: CodePage = crate::include_code_page!(437);
+
+#[cfg(feature="cp667")]
+static CP667: CodePage = crate::include_code_page!(667);
+
+#[cfg(feature="cp720")]
+static CP720: CodePage = crate::include_code_page!(720);
+
+#[cfg(feature="cp737")]
+static CP737: CodePage = crate::include_code_page!(737);
+
+#[cfg(feature="cp775")]
+static CP775: CodePage = crate::include_code_page!(775);
+
+#[cfg(feature="cp790")]
+static CP790: CodePage = crate::include_code_page!(790);
+
+#[cfg(feature="cp808")]
+static CP808: CodePage = crate::include_code_page!(808);
+
+#[cfg(feature="cp850")]
+static CP850: CodePage = crate::include_code_page!(850);
+
+#[cfg(feature="cp852")]
+static CP852: CodePage = crate::include_code_page!(852);
+
+#[cfg(feature="cp855")]
+static CP855: CodePage = crate::include_code_page!(855);
+
+#[cfg(feature="cp856")]
+static CP856: CodePage = crate::include_code_page!(856);
+
+#[cfg(feature="cp857")]
+static CP857: CodePage = crate::include_code_page!(857);
+
+#[cfg(feature="cp858")]
+static CP858: CodePage = crate::include_code_page!(858);
+
+#[cfg(feature="cp860")]
+static CP860: CodePage = crate::include_code_page!(860);
+
+#[cfg(feature="cp861")]
+static CP861: CodePage = crate::include_code_page!(861);
+
+#[cfg(feature="cp862")]
+static CP862: CodePage = crate::include_code_page!(862);
+
+#[cfg(feature="cp863")]
+static CP863: CodePage = crate::include_code_page!(863);
+
+#[cfg(feature="cp864")]
+static CP864: CodePage = crate::include_code_page!(864);
+
+#[cfg(feature="cp865")]
+static CP865: CodePage = crate::include_code_page!(865);
+
+#[cfg(feature="cp866")]
+static CP866: CodePage = crate::include_code_page!(866);
+
+#[cfg(feature="cp867")]
+static CP867: CodePage = crate::include_code_page!(867);
+
+#[cfg(feature="cp869")]
+static CP869: CodePage = crate::include_code_page!(869);
+
+#[cfg(feature="cp874")]
+static CP874: CodePage = crate::include_code_page!(874);
+
+#[cfg(feature="cp895")]
+static CP895: CodePage = crate::include_code_page!(895);
+
+#[cfg(feature="cp912")]
+static CP912: CodePage = crate::include_code_page!(912);
+
+#[cfg(feature="cp915")]
+static CP915: CodePage = crate::include_code_page!(915);
+
+#[cfg(feature="cp1125")]
+static CP1125: CodePage = crate::include_code_page!(1125);
+
+#[cfg(feature="cp1131")]
+static CP1131: CodePage = crate::include_code_page!(1131);
+
+#[cfg(feature="cp3021")]
+static CP3021: CodePage = crate::include_code_page!(3021);
+
+#[cfg(feature="cp1250")]
+static CP1250: CodePage = crate::include_code_page!(1250);
+
+#[cfg(feature="cp1251")]
+static CP1251: CodePage = crate::include_code_page!(1251);
+
+#[cfg(feature="cp1252")]
+static CP1252: CodePage = crate::include_code_page!(1252);
+
+#[cfg(feature="cp1253")]
+static CP1253: CodePage = crate::include_code_page!(1253);
+
+#[cfg(feature="cp1254")]
+static CP1254: CodePage = crate::include_code_page!(1254);
+
+#[cfg(feature="cp1255")]
+static CP1255: CodePage = crate::include_code_page!(1255);
+
+#[cfg(feature="cp1256")]
+static CP1256: CodePage = crate::include_code_page!(1256);
+
+#[cfg(feature="cp1257")]
+static CP1257: CodePage = crate::include_code_page!(1257);
+
+#[cfg(feature="cp1258")]
+static CP1258: CodePage = crate::include_code_page!(1258);
+
+#[cfg(feature="cp10000")]
+static CP10000: CodePage = crate::include_code_page!(10000);
+
+#[cfg(feature="cp10007")]
+static CP10007: CodePage = crate::include_code_page!(10007);
+
+#[cfg(feature="cp20866")]
+static CP20866: CodePage = crate::include_code_page!(20866);
+
+#[cfg(feature="cp21866")]
+static CP21866: CodePage = crate::include_code_page!(21866);
+
+#[cfg(feature="cp28591")]
+static CP28591: CodePage = crate::include_code_page!(28591);
+
+#[cfg(feature="cp28592")]
+static CP28592: CodePage = crate::include_code_page!(28592);
+
+#[cfg(feature="cp28593")]
+static CP28593: CodePage = crate::include_code_page!(28593);
+
+#[cfg(feature="cp28594")]
+static CP28594: CodePage = crate::include_code_page!(28594);
+
+#[cfg(feature="cp28595")]
+static CP28595: CodePage = crate::include_code_page!(28595);
+
+#[cfg(feature="cp28596")]
+static CP28596: CodePage = crate::include_code_page!(28596);
+
+#[cfg(feature="cp28597")]
+static CP28597: CodePage = crate::include_code_page!(28597);
+
+#[cfg(feature="cp28598")]
+static CP28598: CodePage = crate::include_code_page!(28598);
+
+#[cfg(feature="cp28599")]
+static CP28599: CodePage = crate::include_code_page!(28599);
+
+#[cfg(feature="cp28600")]
+static CP28600: CodePage = crate::include_code_page!(28600);
+
+#[cfg(feature="cp28601")]
+static CP28601: CodePage = crate::include_code_page!(28601);
+
+#[cfg(feature="cp28603")]
+static CP28603: CodePage = crate::include_code_page!(28603);
+
+#[cfg(feature="cp28604")]
+static CP28604: CodePage = crate::include_code_page!(28604);
+
+#[cfg(feature="cp28605")]
+static CP28605: CodePage = crate::include_code_page!(28605);
+
+#[cfg(feature="cp28606")]
+static CP28606: CodePage = crate::include_code_page!(28606);
@@ -0,0 +1,244 @@
+use crate::{CODE_PAGE_FILE_HEADER_SIZE, CodePageKind, DecodeError, EncodeError, Encoded, FileHeader, FormatError};
+use crate::{Unmappable, archive_file, encode_str_into};
+#[cfg(feature="load")]
+use crate::{ActiveCodePage, CodePageLoadError, InkeyErr};
+use core::iter::{Copied, FusedIterator};
+use core::num::NonZeroU32;
+use core::slice::{self};
+#[cfg(feature="load")]
+use either::{Either, Left, Right};
+#[cfg(feature="load")]
+use pc_ints::*;
+
+const LEAD_BYTES: usize = 0;
+const UPPER_HALF: usize = 256;
+const REVERSE_PAGES: usize = 512;
+const ROWS_COUNT: usize = 768;
+const REVERSE_PAGES_COUNT: usize = 769;
+const ROWS: usize = 770;
+
+#[doc(hidden)]
+pub const DBCS_HEADER_SIZE: usize = ROWS;
+#[doc(hidden)]
+pub const DBCS_ROW_SIZE: usize = 2 * 192;
+#[doc(hidden)]
+pub const DBCS_REVERSE_PAGE_SIZE: usize = 2 * 256;
+
+/// No double-byte tables are built in, they are read from `CODEPAGE\NNN` files or `CODEPAGE.DAT` archives
+/// made with `dos-cp-generator` from vendor mapping files.
+pub const fn is_dbcs_code_page(code_page: u16) -> bool {
+    matches!(code_page, 932 | 936 | 949 | 950)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DbcsChar {
+    Single(u8),
+    Double(u8, u8),
+}
+
+impl DbcsChar {
+    pub fn write(self, buf: &mut [u8]) -> Option<usize> {
+        match self {
+            DbcsChar::Single(b) => {
+                *buf.first_mut()? = b;
+                Some(1)
+            },
+            DbcsChar::Double(lead, trail) => {
+                buf.get_mut(.. 2)?.copy_from_slice(&[lead, trail]);
+                Some(2)
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct DbcsCodePage([u8]);
+
+impl DbcsCodePage {
+    #[doc(hidden)]
+    pub const fn size(rows: u8, reverse_pages: u8) -> usize {
+        ROWS + rows as usize * DBCS_ROW_SIZE + reverse_pages as usize * DBCS_REVERSE_PAGE_SIZE
+    }
+
+    pub fn new(bytes: &[u8]) -> Option<&DbcsCodePage> {
+        if bytes.len() < DBCS_HEADER_SIZE { return None; }
+        if bytes.len() != Self::size(bytes[ROWS_COUNT], bytes[REVERSE_PAGES_COUNT]) { return None; }
+        if bytes[LEAD_BYTES .. LEAD_BYTES + 256].iter().any(|&row| row > bytes[ROWS_COUNT]) { return None; }
+        if bytes[REVERSE_PAGES .. REVERSE_PAGES + 256].iter().any(|&page| page > bytes[REVERSE_PAGES_COUNT]) {
+            return None;
+        }
+        let chars = bytes[UPPER_HALF .. REVERSE_PAGES].chunks(2)
+            .chain(bytes[ROWS .. ROWS + bytes[ROWS_COUNT] as usize * DBCS_ROW_SIZE].chunks(2));
+        if chars.map(|w| u16::from_be_bytes([w[0], w[1]])).any(|w| char::from_u32(w.into()).is_none()) {
+            return None;
+        }
+        Some(unsafe { &*(bytes as *const [u8] as *const DbcsCodePage) })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<&DbcsCodePage, FormatError> {
+        let header = FileHeader::parse(bytes)?;
+        if header.kind != CodePageKind::DoubleByte { return Err(FormatError::KindMismatch { kind: header.kind }); }
+        let body = &bytes[CODE_PAGE_FILE_HEADER_SIZE ..];
+        header.check(body)?;
+        DbcsCodePage::new(body).ok_or(FormatError::InvalidTable)
+    }
+
+    pub fn from_archive(archive: &[u8], code_page: u16) -> Result<&DbcsCodePage, FormatError> {
+        DbcsCodePage::from_bytes(archive_file(archive, code_page)?)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    const fn table_u16(&self, offset: usize) -> u16 {
+        ((self.0[offset] as u16) << 8) | (self.0[offset + 1] as u16)
+    }
+
+    const fn table_char(&self, offset: usize) -> Option<char> {
+        if let Some(c) = NonZeroU32::new(self.table_u16(offset) as u32) {
+            Some(unsafe { char::from_u32_unchecked(c.get()) })
+        } else {
+            None
+        }
+    }
+
+    pub const fn is_lead_byte(&self, b: u8) -> bool {
+        self.0[LEAD_BYTES + b as usize] != 0
+    }
+
+    pub const fn to_char(&self, b: u8) -> Option<char> {
+        if b >> 7 == 0 {
+            Some(b as char)
+        } else if self.is_lead_byte(b) {
+            None
+        } else {
+            self.table_char(UPPER_HALF + 2 * (b & 0x7F) as usize)
+        }
+    }
+
+    pub const fn to_char_pair(&self, lead: u8, trail: u8) -> Option<char> {
+        let row = self.0[LEAD_BYTES + lead as usize];
+        if row == 0 || trail < 0x40 { return None; }
+        self.table_char(ROWS + (row - 1) as usize * DBCS_ROW_SIZE + 2 * (trail - 0x40) as usize)
+    }
+
+    pub const fn from_char(&self, c: char) -> Option<DbcsChar> {
+        if (c as u32) >> 7 == 0 { return Some(DbcsChar::Single(c as u32 as u8)); }
+        if (c as u32) >> 16 != 0 { return None; }
+        let page = self.0[REVERSE_PAGES + ((c as u32) >> 8) as usize];
+        if page == 0 { return None; }
+        let reverse_pages = ROWS + self.0[ROWS_COUNT] as usize * DBCS_ROW_SIZE;
+        let code = self.table_u16(
+            reverse_pages + (page - 1) as usize * DBCS_REVERSE_PAGE_SIZE + 2 * ((c as u32) & 0xFF) as usize
+        );
+        if code == 0 {
+            None
+        } else if code >> 8 == 0 {
+            Some(DbcsChar::Single(code as u8))
+        } else {
+            Some(DbcsChar::Double((code >> 8) as u8, code as u8))
+        }
+    }
+
+    pub fn decode<'a>(&'a self, bytes: &'a [u8]) -> DbcsDecode<'a, Copied<slice::Iter<'a, u8>>> {
+        DbcsDecode::new(self, bytes.iter().copied())
+    }
+
+    pub fn decode_iter<I: IntoIterator<Item=u8>>(&self, bytes: I) -> DbcsDecode<'_, I::IntoIter> {
+        DbcsDecode::new(self, bytes.into_iter())
+    }
+
+    fn encode_char(&self, c: char, buf: &mut [u8]) -> Option<usize> {
+        self.from_char(c)?.write(buf)
+    }
+
+    pub fn encode_into(&self, s: &str, buf: &mut [u8], unmappable: Unmappable) -> Result<Encoded, EncodeError> {
+        encode_str_into(s, buf, unmappable, |c, buf| self.encode_char(c, buf))
+    }
+
+    #[cfg(feature="best-fit")]
+    pub fn best_fit(&self, c: char, buf: &mut [u8]) -> Option<usize> {
+        crate::best_fit::best_fit(c, buf, |c, buf| self.encode_char(c, buf))
+    }
+
+    #[cfg(feature="load")]
+    pub fn load() -> Result<&'static DbcsCodePage, CodePageLoadError> {
+        match ActiveCodePage::load()? {
+            (_, ActiveCodePage::Dbcs(code_page)) => Ok(code_page),
+            (code_page, ActiveCodePage::Sbcs(_)) => Err(CodePageLoadError::CodePageIsNotDbcs { code_page }),
+        }
+    }
+
+    #[cfg(feature="load")]
+    pub fn inkey(&self) -> Result<Option<Either<u8, char>>, InkeyErr> {
+        let c = int_21h_ah_06h_dl_FFh_inkey().map_err(|_| InkeyErr)?;
+        let c = match c {
+            Some(x) => x.al_char,
+            None => return Ok(None),
+        };
+        if c == 0 {
+            let c = int_21h_ah_06h_dl_FFh_inkey().map_err(|_| InkeyErr)?;
+            let c = c.ok_or(InkeyErr)?.al_char;
+            Ok(Some(Left(c)))
+        } else if self.is_lead_byte(c) {
+            let trail = loop {
+                if let Some(x) = int_21h_ah_06h_dl_FFh_inkey().map_err(|_| InkeyErr)? {
+                    break x.al_char;
+                }
+            };
+            Ok(self.to_char_pair(c, trail).map(Right))
+        } else {
+            Ok(self.to_char(c).map(Right))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DbcsDecode<'a, I> {
+    code_page: &'a DbcsCodePage,
+    bytes: I,
+    offset: usize,
+    pending: Option<u8>,
+}
+
+impl<'a, I> DbcsDecode<'a, I> {
+    fn new(code_page: &'a DbcsCodePage, bytes: I) -> Self {
+        DbcsDecode { code_page, bytes, offset: 0, pending: None }
+    }
+
+    pub fn offset(&self) -> usize { self.offset }
+}
+
+impl<'a, I: Iterator<Item=u8>> Iterator for DbcsDecode<'a, I> {
+    type Item = Result<char, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let byte = self.pending.take().or_else(|| self.bytes.next())?;
+        let offset = self.offset;
+        self.offset += 1;
+        if !self.code_page.is_lead_byte(byte) {
+            return Some(self.code_page.to_char(byte).ok_or(DecodeError { offset, byte }));
+        }
+        match self.bytes.next() {
+            None => Some(Err(DecodeError { offset, byte })),
+            Some(trail) if trail < 0x40 => {
+                self.pending = Some(trail);
+                Some(Err(DecodeError { offset, byte }))
+            },
+            Some(trail) => {
+                self.offset += 1;
+                Some(self.code_page.to_char_pair(byte, trail).ok_or(DecodeError { offset, byte }))
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending.is_some() as usize;
+        let (min, max) = self.bytes.size_hint();
+        ((min + pending).div_ceil(2), max.and_then(|max| max.checked_add(pending)))
+    }
+}
+
+impl<'a, I: FusedIterator<Item=u8>> FusedIterator for DbcsDecode<'a, I> { }
@@ -0,0 +1,39 @@
+use crate::ints::lsl_segment_limit;
+use core::slice::{self};
+use pc_ints::*;
+
+const MAX_ENVIRONMENT_SIZE: usize = 0x8000;
+
+fn segment_base(selector: u16) -> Option<usize> {
+    let addr = int_31h_ax_0006h_segment_addr(selector).ok()?;
+    Some((((addr.cx_segment as u32) << 16) | addr.dx_offset as u32) as usize)
+}
+
+// Variables block (`NAME=VALUE\0...\0`) followed by the program path.
+fn environment() -> Option<(&'static [u8], Option<&'static [u8]>)> {
+    let psp = segment_base(int_21h_ah_62h_psp_addr().bx_segment)?;
+    let env_selector = unsafe { ((psp + 0x2C) as *const u16).read_unaligned() };
+    if env_selector == 0 { return None; }
+    let env_size = (lsl_segment_limit(env_selector)? as usize).saturating_add(1).min(MAX_ENVIRONMENT_SIZE);
+    let env = unsafe { slice::from_raw_parts(segment_base(env_selector)? as *const u8, env_size) };
+    let vars_len = if env[0] == 0 { 0 } else { env.windows(2).position(|x| x == [0, 0])? + 1 };
+    let program = env.get(vars_len + 3 ..).and_then(|tail| {
+        let len = tail.iter().position(|&b| b == 0)?;
+        Some(&tail[.. len])
+    });
+    Some((&env[.. vars_len], program))
+}
+
+pub(crate) fn env_var(name: &[u8]) -> Option<&'static [u8]> {
+    let (vars, _) = environment()?;
+    vars.split(|&b| b == 0).find_map(|var| {
+        var.strip_prefix(name)?.strip_prefix(b"=")
+    })
+}
+
+pub(crate) fn program_dir() -> Option<&'static [u8]> {
+    let (_, program) = environment()?;
+    let program = program?;
+    let len = program.iter().rposition(|&b| b == b'\\')?;
+    Some(&program[.. len])
+}
@@ -0,0 +1,178 @@
+use crate::Inconsistency;
+use core::fmt::{self, Display, Formatter};
+
+pub const CODE_PAGE_FILE_MAGIC: [u8; 4] = *b"DOCP";
+
+pub const CODE_PAGE_FILE_VERSION: u8 = 2;
+
+pub const CODE_PAGE_FILE_HEADER_SIZE: usize = 16;
+
+pub const CODE_PAGE_ARCHIVE_MAGIC: [u8; 4] = *b"DOCA";
+
+pub const CODE_PAGE_ARCHIVE_HEADER_SIZE: usize = 8;
+
+pub const CODE_PAGE_ARCHIVE_ENTRY_SIZE: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodePageKind {
+    SingleByte,
+    DoubleByte,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormatError {
+    TooShort,
+    InvalidMagic,
+    UnsupportedVersion { version: u8 },
+    UnknownKind { kind: u8 },
+    KindMismatch { kind: CodePageKind },
+    InvalidLength { len: u32 },
+    ChecksumMismatch { expected: u32, actual: u32 },
+    InvalidHashParam { hash_param: u16 },
+    Inconsistent { inconsistency: Inconsistency },
+    InvalidTable,
+    MissingCodePage { code_page: u16 },
+    CodePageMismatch { file_code_page: u16 },
+}
+
+impl Display for FormatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            FormatError::TooShort => write!(f, "unexpected end of data"),
+            FormatError::InvalidMagic => write!(f, "invalid signature"),
+            FormatError::UnsupportedVersion { version } => write!(f, "unsupported format version {version}"),
+            FormatError::UnknownKind { kind } => write!(f, "unknown code page kind {kind}"),
+            FormatError::KindMismatch { kind: CodePageKind::SingleByte } =>
+                write!(f, "single-byte code page where double-byte one expected"),
+            FormatError::KindMismatch { kind: CodePageKind::DoubleByte } =>
+                write!(f, "double-byte code page where single-byte one expected"),
+            FormatError::InvalidLength { len } => write!(f, "invalid table length {len}"),
+            FormatError::ChecksumMismatch { expected, actual } =>
+                write!(f, "checksum mismatch (expected {expected:08X}h, actual {actual:08X}h)"),
+            FormatError::InvalidHashParam { hash_param } => write!(f, "invalid hash param {hash_param:04X}h"),
+            FormatError::Inconsistent { inconsistency } => write!(f, "{inconsistency}"),
+            FormatError::InvalidTable => write!(f, "invalid table"),
+            FormatError::MissingCodePage { code_page } => write!(f, "code page {code_page} is missing in the archive"),
+            FormatError::CodePageMismatch { file_code_page } =>
+                write!(f, "archive entry contains code page {file_code_page}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FileHeader {
+    pub(crate) kind: CodePageKind,
+    #[cfg_attr(not(feature="load"), allow(dead_code))]
+    pub(crate) code_page: u16,
+    pub(crate) len: u32,
+    pub(crate) checksum: u32,
+}
+
+impl FileHeader {
+    pub(crate) fn parse(bytes: &[u8]) -> Result<FileHeader, FormatError> {
+        let header = bytes.get(.. CODE_PAGE_FILE_HEADER_SIZE).ok_or(FormatError::TooShort)?;
+        if header[.. 4] != CODE_PAGE_FILE_MAGIC { return Err(FormatError::InvalidMagic); }
+        if header[4] != CODE_PAGE_FILE_VERSION {
+            return Err(FormatError::UnsupportedVersion { version: header[4] });
+        }
+        let kind = match header[5] {
+            0 => CodePageKind::SingleByte,
+            1 => CodePageKind::DoubleByte,
+            kind => return Err(FormatError::UnknownKind { kind }),
+        };
+        Ok(FileHeader {
+            kind,
+            code_page: u16::from_le_bytes([header[6], header[7]]),
+            len: u32::from_le_bytes([header[8], header[9], header[10], header[11]]),
+            checksum: u32::from_le_bytes([header[12], header[13], header[14], header[15]]),
+        })
+    }
+
+    pub(crate) fn check(&self, body: &[u8]) -> Result<(), FormatError> {
+        if body.len() != self.len as usize { return Err(FormatError::InvalidLength { len: self.len }); }
+        let actual = checksum(body);
+        if actual != self.checksum {
+            return Err(FormatError::ChecksumMismatch { expected: self.checksum, actual });
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn parse_archive_header(bytes: &[u8]) -> Result<u16, FormatError> {
+    let header = bytes.get(.. CODE_PAGE_ARCHIVE_HEADER_SIZE).ok_or(FormatError::TooShort)?;
+    if header[.. 4] != CODE_PAGE_ARCHIVE_MAGIC { return Err(FormatError::InvalidMagic); }
+    if header[4] != CODE_PAGE_FILE_VERSION {
+        return Err(FormatError::UnsupportedVersion { version: header[4] });
+    }
+    Ok(u16::from_le_bytes([header[6], header[7]]))
+}
+
+pub(crate) fn parse_archive_entry(entry: &[u8; CODE_PAGE_ARCHIVE_ENTRY_SIZE]) -> (u16, u32) {
+    (u16::from_le_bytes([entry[0], entry[1]]), u32::from_le_bytes([entry[4], entry[5], entry[6], entry[7]]))
+}
+
+/// Finds the file of the given code page in an archive, the header included.
+pub(crate) fn archive_file(archive: &[u8], code_page: u16) -> Result<&[u8], FormatError> {
+    let count = parse_archive_header(archive)?;
+    let index = archive.get(
+        CODE_PAGE_ARCHIVE_HEADER_SIZE .. CODE_PAGE_ARCHIVE_HEADER_SIZE + count as usize * CODE_PAGE_ARCHIVE_ENTRY_SIZE
+    ).ok_or(FormatError::TooShort)?;
+    let (_, offset) = index.chunks_exact(CODE_PAGE_ARCHIVE_ENTRY_SIZE)
+        .map(|entry| parse_archive_entry(entry.try_into().unwrap()))
+        .find(|&(n, _)| n == code_page)
+        .ok_or(FormatError::MissingCodePage { code_page })?;
+    let file = archive.get(offset as usize ..).ok_or(FormatError::TooShort)?;
+    let header = FileHeader::parse(file)?;
+    if header.code_page != code_page {
+        return Err(FormatError::CodePageMismatch { file_code_page: header.code_page });
+    }
+    let len = CODE_PAGE_FILE_HEADER_SIZE.checked_add(header.len as usize).ok_or(FormatError::TooShort)?;
+    file.get(.. len).ok_or(FormatError::TooShort)
+}
+
+#[doc(hidden)]
+pub fn archive_header(count: u16) -> [u8; CODE_PAGE_ARCHIVE_HEADER_SIZE] {
+    let mut header = [0; CODE_PAGE_ARCHIVE_HEADER_SIZE];
+    header[.. 4].copy_from_slice(&CODE_PAGE_ARCHIVE_MAGIC);
+    header[4] = CODE_PAGE_FILE_VERSION;
+    header[6 .. 8].copy_from_slice(&count.to_le_bytes());
+    header
+}
+
+#[doc(hidden)]
+pub fn archive_entry(code_page: u16, offset: u32) -> [u8; CODE_PAGE_ARCHIVE_ENTRY_SIZE] {
+    let mut entry = [0; CODE_PAGE_ARCHIVE_ENTRY_SIZE];
+    entry[.. 2].copy_from_slice(&code_page.to_le_bytes());
+    entry[4 ..].copy_from_slice(&offset.to_le_bytes());
+    entry
+}
+
+#[doc(hidden)]
+pub fn file_header(code_page: u16, kind: CodePageKind, body: &[u8]) -> [u8; CODE_PAGE_FILE_HEADER_SIZE] {
+    let mut header = [0; CODE_PAGE_FILE_HEADER_SIZE];
+    header[.. 4].copy_from_slice(&CODE_PAGE_FILE_MAGIC);
+    header[4] = CODE_PAGE_FILE_VERSION;
+    header[5] = match kind {
+        CodePageKind::SingleByte => 0,
+        CodePageKind::DoubleByte => 1,
+    };
+    header[6 .. 8].copy_from_slice(&code_page.to_le_bytes());
+    header[8 .. 12].copy_from_slice(&u32::try_from(body.len()).unwrap().to_le_bytes());
+    header[12 .. 16].copy_from_slice(&checksum(body).to_le_bytes());
+    header
+}
+
+// Adler-32.
+fn checksum(bytes: &[u8]) -> u32 {
+    let mut a = 1u32;
+    let mut b = 0u32;
+    for chunk in bytes.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
@@ -0,0 +1,98 @@
+#[cfg(target_os="dos")]
+use core::arch::asm;
+use pc_ints::AxErr;
+
+#[cfg(target_os="dos")]
+const CF: u8 = 0x01;
+
+#[derive(Debug, Clone)]
+pub(crate) struct DxAxPos {
+    #[allow(dead_code)]
+    pub(crate) dx_ax_pos: u32,
+}
+
+#[cfg(not(target_os="dos"))]
+#[allow(unused_variables)]
+pub(crate) fn int_21h_ax_4200h_seek(bx_handle: u16, cx_dx_pos: u32) -> Result<DxAxPos, AxErr> {
+    panic!("cfg(target_os=\"dos\")");
+}
+
+#[cfg(target_os="dos")]
+#[inline]
+pub(crate) fn int_21h_ax_4200h_seek(bx_handle: u16, cx_dx_pos: u32) -> Result<DxAxPos, AxErr> {
+    let mut ax: u16;
+    let mut dx: u16;
+    let mut flags: u16;
+    unsafe {
+        asm!(
+            "int 0x21",
+            "mov {ax:x}, ax",
+            "lahf",
+            ax = lateout(reg) ax,
+            in("ax") 0x4200u16,
+            in("bx") bx_handle,
+            in("cx") (cx_dx_pos >> 16) as u16,
+            inout("dx") cx_dx_pos as u16 => dx,
+            lateout("ax") flags,
+        );
+    }
+    if ((flags >> 8) as u8) & CF == 0 {
+        Ok(DxAxPos { dx_ax_pos: ((dx as u32) << 16) | ax as u32 })
+    } else {
+        Err(AxErr { ax_err: ax })
+    }
+}
+
+#[cfg(not(target_os="dos"))]
+#[allow(unused_variables)]
+pub(crate) fn int_21h_ax_6602h_set_code_page(bx_active: u16, dx_default: u16) -> Result<(), AxErr> {
+    panic!("cfg(target_os=\"dos\")");
+}
+
+#[cfg(target_os="dos")]
+#[inline]
+pub(crate) fn int_21h_ax_6602h_set_code_page(bx_active: u16, dx_default: u16) -> Result<(), AxErr> {
+    let mut flags: u16;
+    let mut ax_err: u16;
+    unsafe {
+        asm!(
+            "int 0x21",
+            "mov {ax_err:x}, ax",
+            "lahf",
+            ax_err = lateout(reg) ax_err,
+            in("ax") 0x6602u16,
+            in("bx") bx_active,
+            in("dx") dx_default,
+            lateout("ax") flags,
+        );
+    }
+    if ((flags >> 8) as u8) & CF == 0 {
+        Ok(())
+    } else {
+        Err(AxErr { ax_err })
+    }
+}
+
+#[cfg(not(target_os="dos"))]
+#[allow(unused_variables)]
+pub(crate) fn lsl_segment_limit(selector: u16) -> Option<u32> {
+    panic!("cfg(target_os=\"dos\")");
+}
+
+#[cfg(target_os="dos")]
+#[inline]
+pub(crate) fn lsl_segment_limit(selector: u16) -> Option<u32> {
+    let limit: u32;
+    let loaded: u8;
+    unsafe {
+        asm!(
+            "lsl {limit:e}, {selector:e}",
+            "setz {loaded}",
+            selector = in(reg) selector as u32,
+            limit = lateout(reg) limit,
+            loaded = lateout(reg_byte) loaded,
+            options(nomem, nostack),
+        );
+    }
+    if loaded != 0 { Some(limit) } else { None }
+}
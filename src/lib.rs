@@ -5,17 +5,15 @@
 
 #![no_std]
 
+use core::fmt::{self, Display, Formatter};
 #[cfg(feature="load")]
-use core::fmt::{self, Debug, Display, Formatter};
+use core::fmt::Debug;
+use core::iter::{Copied, FusedIterator, Peekable};
 #[cfg(feature="load")]
-use core::mem::{MaybeUninit, forget, transmute};
+use core::mem::{MaybeUninit, transmute};
 use core::num::NonZeroU32;
-#[cfg(feature="load")]
-use core::ptr::{self};
-#[cfg(feature="load")]
 use core::slice::{self};
-#[cfg(feature="load")]
-use core::sync::atomic::{AtomicBool, Ordering};
+use core::str::CharIndices;
 #[cfg(feature="load")]
 use either::{Either, Left, Right};
 #[cfg(feature="load")]
@@ -26,6 +24,39 @@ use iter_identify_first_last::IteratorIdentifyFirstLastExt;
 use panicking::panicking;
 #[cfg(feature="load")]
 use pc_ints::*;
+#[cfg(feature="load")]
+use ints::*;
+#[cfg(feature="load")]
+use lock::SpinLock;
+#[cfg(feature="best-fit")]
+use best_fit::best_fit;
+
+#[cfg(feature="best-fit")]
+mod best_fit;
+mod builtin;
+mod format;
+#[cfg(feature="load")]
+mod ints;
+mod verify;
+#[doc(hidden)]
+pub mod tables;
+#[cfg(feature="compose")]
+mod compose;
+mod dbcs;
+mod names;
+#[cfg(feature="load")]
+mod env;
+#[cfg(feature="load")]
+mod search;
+#[cfg(feature="load")]
+mod lock;
+
+pub use dbcs::*;
+pub use format::*;
+pub use verify::*;
+pub use names::*;
+#[cfg(feature="load")]
+pub use search::*;
 
 #[doc(hidden)]
 pub use core::write as std_write;
@@ -39,15 +70,43 @@ pub const fn hash(w: u16, p: u16) -> u8 {
     ((w ^ (w >> 8)) & 0x007F) as u8
 }
 
-const CODE_PAGE_SIZE: u16 = 512;
+const CODE_PAGE_SIZE: u16 = 896;
+
+const PLAIN_CODE_PAGE_SIZE: u16 = 640;
+
+const BUCKETS: usize = 256;
+
+const HASH_PARAM: usize = 510;
+
+const OVERFLOW: usize = 512;
+
+const OVERFLOW_SIZE: usize = 128;
+
+const HASH_PARAM_SEARCH_LIMIT: u16 = 1024;
+
+#[doc(hidden)]
+pub const HASH_PARAM_ASCII_LOW_HALF: u16 = 0x8000;
+
+const CONTROL_GLYPHS: [char; 32] = [
+    '\0', '☺', '☻', '♥', '♦', '♣', '♠', '•',
+    '◘', '○', '◙', '♂', '♀', '♪', '♫', '☼',
+    '►', '◄', '↕', '‼', '¶', '§', '▬', '↨',
+    '↑', '↓', '→', '←', '∟', '↔', '▲', '▼',
+];
+
+const DEL_GLYPH: char = '⌂';
+
+enum TableError {
+    TooBigChar,
+    DuplicateChar,
+}
 
 #[derive(Debug, Clone)]
 #[repr(C, align(8))]
-pub struct CodePage(pub [u8; CODE_PAGE_SIZE as _]);
+pub struct CodePage([u8; CODE_PAGE_SIZE as _]);
 
 impl CodePage {
-    const fn to_upper_half_char(&self, c: u8) -> Option<char> {
-        let offset = 2 * c as usize;
+    const fn table_char(&self, offset: usize) -> Option<char> {
         let hb = self.0[offset];
         let lb = self.0[offset + 1];
         if let Some(c) = NonZeroU32::new(((hb as u32) << 8) | (lb as u32)) {
@@ -57,36 +116,347 @@ impl CodePage {
         }
     }
 
+    const fn to_upper_half_char(&self, c: u8) -> Option<char> {
+        self.table_char(2 * c as usize)
+    }
+
+    const fn to_lower_half_char(&self, c: u8) -> Option<char> {
+        if c == 0 || self.has_ascii_low_half() {
+            Some(c as char)
+        } else {
+            self.table_char(PLAIN_CODE_PAGE_SIZE as usize + 2 * c as usize)
+        }
+    }
+
+    const fn hash_param(&self) -> u16 {
+        (self.0[HASH_PARAM] as u16) | ((self.0[HASH_PARAM + 1] as u16) << 8)
+    }
+
+    pub fn builtin(code_page: u16) -> Option<&'static CodePage> {
+        builtin::builtin(code_page)
+    }
+
+    /// Pseudo code page with the upper half undefined.
+    pub fn ascii() -> &'static CodePage {
+        static ASCII: CodePage = CodePage::new(&['?'; 128]);
+        &ASCII
+    }
+
+    #[doc(hidden)]
+    pub const unsafe fn from_raw(bytes: [u8; CODE_PAGE_SIZE as usize]) -> CodePage {
+        CodePage(bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<CodePage, FormatError> {
+        let header = FileHeader::parse(bytes)?;
+        if header.kind != CodePageKind::SingleByte { return Err(FormatError::KindMismatch { kind: header.kind }); }
+        let body = &bytes[CODE_PAGE_FILE_HEADER_SIZE ..];
+        header.check(body)?;
+        if body.len() != PLAIN_CODE_PAGE_SIZE as usize && body.len() != CODE_PAGE_SIZE as usize {
+            return Err(FormatError::InvalidLength { len: header.len });
+        }
+        let mut code_page = CodePage([0; CODE_PAGE_SIZE as usize]);
+        code_page.0[.. body.len()].copy_from_slice(body);
+        code_page.validate(body.len())?;
+        Ok(code_page)
+    }
+
+    pub fn from_archive(archive: &[u8], code_page: u16) -> Result<CodePage, FormatError> {
+        CodePage::from_bytes(archive_file(archive, code_page)?)
+    }
+
+    fn validate(&self, len: usize) -> Result<(), FormatError> {
+        let hash_param = self.hash_param();
+        if (hash_param & HASH_PARAM_ASCII_LOW_HALF != 0) != (len == PLAIN_CODE_PAGE_SIZE as usize) {
+            return Err(FormatError::InvalidHashParam { hash_param });
+        }
+        if let Some(inconsistency) = self.verify().next() {
+            return Err(FormatError::Inconsistent { inconsistency });
+        }
+        Ok(())
+    }
+
+    /// Undefined bytes are marked with `'?'`.
+    ///
+    /// Panics if a char is mapped from more than one byte, so in a `const` or `static` this is a compile error.
+    pub const fn new(upper_half: &[char; 128]) -> CodePage {
+        let Some(hash_param) = CodePage::find_hash_param(upper_half) else {
+            panic!("too big char, bit needs to be preremapped")
+        };
+        CodePage::with_hash_param(upper_half, hash_param)
+    }
+
+    /// Prefers a param which leaves the overflow area empty,
+    /// otherwise picks the one putting the fewest chars into it.
+    ///
+    /// Only the first few hundred params are tried, so the search stays cheap enough for a `const` or `static`.
+    /// Any table fits with any param, so `None` is returned only for chars outside the BMP.
+    pub const fn find_hash_param(upper_half: &[char; 128]) -> Option<u16> {
+        let mut best: Option<(u16, usize)> = None;
+        let mut hash_param = 0;
+        while hash_param < HASH_PARAM_SEARCH_LIMIT {
+            match CodePage::overflow_len(upper_half, hash_param) {
+                Err(_) => return None,
+                Ok(0) => return Some(hash_param | HASH_PARAM_ASCII_LOW_HALF),
+                Ok(overflow_len) => {
+                    let better = match best {
+                        Some((_, best_overflow_len)) => overflow_len < best_overflow_len,
+                        None => true,
+                    };
+                    if better { best = Some((hash_param, overflow_len)); }
+                },
+            }
+            hash_param += 1;
+        }
+        let Some((hash_param, _)) = best else { return None; };
+        Some(hash_param | HASH_PARAM_ASCII_LOW_HALF)
+    }
+
+    const fn overflow_len(upper_half: &[char; 128], hash_param: u16) -> Result<usize, TableError> {
+        let mut filled = [0u8; 128];
+        let mut overflow_len = 0;
+        let mut i = 0;
+        while i < 128 {
+            let c = upper_half[i];
+            if c != '?' {
+                if (c as u32) >> 16 != 0 { return Err(TableError::TooBigChar); }
+                let bucket = hash(c as u32 as u16, hash_param) as usize;
+                if bucket == 0x7F || filled[bucket] == 2 {
+                    overflow_len += 1;
+                } else {
+                    filled[bucket] += 1;
+                }
+            }
+            i += 1;
+        }
+        Ok(overflow_len)
+    }
+
+    pub const fn with_hash_param(upper_half: &[char; 128], hash_param: u16) -> CodePage {
+        match CodePage::try_with_hash_param(upper_half, hash_param) {
+            Ok(code_page) => code_page,
+            Err(TableError::TooBigChar) => panic!("too big char, bit needs to be preremapped"),
+            Err(TableError::DuplicateChar) => panic!("duplicate char in code page table"),
+        }
+    }
+
+    const fn try_with_hash_param(upper_half: &[char; 128], hash_param: u16) -> Result<CodePage, TableError> {
+        let hash_param = hash_param | HASH_PARAM_ASCII_LOW_HALF;
+        let mut res = [0; CODE_PAGE_SIZE as usize];
+        res[HASH_PARAM] = hash_param as u8;
+        res[HASH_PARAM + 1] = (hash_param >> 8) as u8;
+        let mut i = BUCKETS;
+        while i < HASH_PARAM {
+            res[i] = 128;
+            i += 1;
+        }
+        let mut i = OVERFLOW;
+        while i < OVERFLOW + OVERFLOW_SIZE {
+            res[i] = 128;
+            i += 1;
+        }
+        let mut overflow_len = 0;
+        let mut i = 0;
+        while i < 128 {
+            let c = upper_half[i];
+            if c != '?' {
+                if (c as u32) >> 16 != 0 { return Err(TableError::TooBigChar); }
+                let mut j = 0;
+                while j < i {
+                    if upper_half[j] == c { return Err(TableError::DuplicateChar); }
+                    j += 1;
+                }
+                let w = c as u32 as u16;
+                res[2 * i] = (w >> 8) as u8;
+                res[2 * i + 1] = w as u8;
+                let bucket = hash(w, hash_param) as usize;
+                if bucket != 0x7F && res[BUCKETS + 2 * bucket] == 128 {
+                    res[BUCKETS + 2 * bucket] = i as u8;
+                } else if bucket != 0x7F && res[BUCKETS + 2 * bucket + 1] == 128 {
+                    res[BUCKETS + 2 * bucket + 1] = i as u8;
+                } else {
+                    // Insertion sort, the overflow area is kept ordered by char.
+                    let mut j = overflow_len;
+                    while j > 0 && upper_half[res[OVERFLOW + j - 1] as usize] as u32 > w as u32 {
+                        res[OVERFLOW + j] = res[OVERFLOW + j - 1];
+                        j -= 1;
+                    }
+                    res[OVERFLOW + j] = i as u8;
+                    overflow_len += 1;
+                }
+            }
+            i += 1;
+        }
+        Ok(CodePage(res))
+    }
+
+    pub const fn with_low_half_overrides(mut self, overrides: &[(u8, char)]) -> CodePage {
+        if overrides.is_empty() { return self; }
+        let hash_param = self.hash_param() & !HASH_PARAM_ASCII_LOW_HALF;
+        self.0[HASH_PARAM] = hash_param as u8;
+        self.0[HASH_PARAM + 1] = (hash_param >> 8) as u8;
+        let mut i = 1;
+        while i < 128 {
+            self.0[PLAIN_CODE_PAGE_SIZE as usize + 2 * i + 1] = i as u8;
+            i += 1;
+        }
+        let mut i = 0;
+        while i < overrides.len() {
+            let (b, c) = overrides[i];
+            if b == 0 || b >> 7 != 0 { panic!("invalid low half override"); }
+            if (c as u32) >> 16 != 0 { panic!("too big char, bit needs to be preremapped"); }
+            let w = c as u32 as u16;
+            self.0[PLAIN_CODE_PAGE_SIZE as usize + 2 * b as usize] = (w >> 8) as u8;
+            self.0[PLAIN_CODE_PAGE_SIZE as usize + 2 * b as usize + 1] = w as u8;
+            i += 1;
+        }
+        self
+    }
+
+    pub const fn has_ascii_low_half(&self) -> bool {
+        self.hash_param() & HASH_PARAM_ASCII_LOW_HALF != 0
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = if self.has_ascii_low_half() { PLAIN_CODE_PAGE_SIZE } else { CODE_PAGE_SIZE };
+        &self.0[.. len as usize]
+    }
+
     pub const fn to_char(&self, c: u8) -> Option<char> {
         let half = c & 0x7F;
         if c == half {
-            Some(c as char)
+            self.to_lower_half_char(half)
         } else {
             self.to_upper_half_char(half)
         }
     }
 
+    pub const fn to_display_char(&self, c: u8) -> Option<char> {
+        if c >> 5 == 0 {
+            Some(CONTROL_GLYPHS[c as usize])
+        } else if c == 0x7F {
+            Some(DEL_GLYPH)
+        } else {
+            self.to_char(c)
+        }
+    }
+
+    pub fn decode<'a>(&'a self, bytes: &'a [u8]) -> Decode<'a, Copied<slice::Iter<'a, u8>>> {
+        Decode::new(self, bytes.iter().copied())
+    }
+
+    pub fn decode_iter<I: IntoIterator<Item=u8>>(&self, bytes: I) -> Decode<'_, I::IntoIter> {
+        Decode::new(self, bytes.into_iter())
+    }
+
     pub const fn from_char(&self, c: char) -> Option<u8> {
         if (c as u32) >> 7 == 0 {
-            Some(c as u32 as u8)
-        } else if (c as u32) >> 16 != 0 {
-            None
-        } else {
-            let w = (c as u32) as u16;
-            let hash_param = (self.0[510] as u16) | ((self.0[511] as u16) << 8);
-            let offset = 256 + 2 * hash(w, hash_param) as usize;
-            let try_1 = self.0[offset];
-            if try_1 >> 7 != 0 { return None; }
-            if let Some(x) = self.to_upper_half_char(try_1) {
-                if x == c { return Some(0x80 | try_1); }
+            if let Some(x) = self.to_lower_half_char(c as u32 as u8) {
+                if x == c { return Some(c as u32 as u8); }
             }
-            let try_2 = self.0[offset + 1];
-            if try_2 >> 7 != 0 { return None; }
-            if let Some(x) = self.to_upper_half_char(try_2) {
-                if x == c { return Some(0x80 | try_2); }
+        }
+        if (c as u32) >> 16 != 0 { return None; }
+        if let Some(b) = self.upper_half_byte(c) { return Some(b); }
+        if self.has_ascii_low_half() { return None; }
+        let mut i = 1;
+        while i < 0x80 {
+            if let Some(x) = self.to_lower_half_char(i) {
+                if x == c { return Some(i); }
             }
-            None
+            i += 1;
+        }
+        None
+    }
+
+    const fn upper_half_byte(&self, c: char) -> Option<u8> {
+        let w = (c as u32) as u16;
+        let bucket = hash(w, self.hash_param()) as usize;
+        if bucket != 0x7F {
+            if let Ok(b) = self.bucket_byte(BUCKETS + 2 * bucket, c) { return b; }
+        }
+        self.overflow_byte(c)
+    }
+
+    // Binary search with a fixed number of steps, unused slots are at the end.
+    const fn overflow_byte(&self, c: char) -> Option<u8> {
+        let mut start = 0;
+        let mut step = OVERFLOW_SIZE / 2;
+        while step != 0 {
+            if self.overflow_char(start + step - 1) < c as u32 { start += step; }
+            step /= 2;
+        }
+        let slot = self.0[OVERFLOW + start];
+        if self.overflow_char(start) == c as u32 { Some(0x80 | slot) } else { None }
+    }
+
+    const fn overflow_char(&self, index: usize) -> u32 {
+        let slot = self.0[OVERFLOW + index];
+        if slot >> 7 != 0 { return u32::MAX; }
+        match self.to_upper_half_char(slot) {
+            Some(c) => c as u32,
+            None => u32::MAX,
+        }
+    }
+
+    // Returns `Err` if the bucket is full and does not contain `c`.
+    const fn bucket_byte(&self, offset: usize, c: char) -> Result<Option<u8>, ()> {
+        let try_1 = self.0[offset];
+        if try_1 >> 7 != 0 { return Ok(None); }
+        if let Some(x) = self.to_upper_half_char(try_1) {
+            if x == c { return Ok(Some(0x80 | try_1)); }
+        }
+        let try_2 = self.0[offset + 1];
+        if try_2 >> 7 != 0 { return Ok(None); }
+        if let Some(x) = self.to_upper_half_char(try_2) {
+            if x == c { return Ok(Some(0x80 | try_2)); }
+        }
+        Err(())
+    }
+
+    pub const fn from_display_char(&self, c: char) -> Option<u8> {
+        if c != '\0' && ((c as u32) >> 5 == 0 || c as u32 == 0x7F) { return None; }
+        if let Some(b) = self.from_char(c) { return Some(b); }
+        if c == DEL_GLYPH { return Some(0x7F); }
+        let mut i = 1;
+        while i < CONTROL_GLYPHS.len() {
+            if CONTROL_GLYPHS[i] == c { return Some(i as u8); }
+            i += 1;
+        }
+        None
+    }
+
+    pub const fn can_encode(&self, s: &str) -> Result<(), (usize, char)> {
+        match self.first_unmappable(s, 0) {
+            Some(unmappable) => Err(unmappable),
+            None => Ok(()),
+        }
+    }
+
+    pub const fn coverage<'a>(&'a self, s: &'a str) -> Coverage<'a> {
+        Coverage { code_page: self, s, index: 0 }
+    }
+
+    const fn first_unmappable(&self, s: &str, mut index: usize) -> Option<(usize, char)> {
+        while index < s.len() {
+            let c = str_char_at(s, index);
+            if self.from_char(c).is_none() { return Some((index, c)); }
+            index += c.len_utf8();
         }
+        None
+    }
+
+    fn encode_char(&self, c: char, buf: &mut [u8]) -> Option<usize> {
+        *buf.first_mut()? = self.from_char(c)?;
+        Some(1)
+    }
+
+    pub fn encode_into(&self, s: &str, buf: &mut [u8], unmappable: Unmappable) -> Result<Encoded, EncodeError> {
+        encode_str_into(s, buf, unmappable, |c, buf| self.encode_char(c, buf))
+    }
+
+    #[cfg(feature="best-fit")]
+    pub fn best_fit(&self, c: char, buf: &mut [u8]) -> Option<usize> {
+        best_fit::best_fit(c, buf, |c, buf| self.encode_char(c, buf))
     }
 
     #[cfg(feature="load")]
@@ -102,64 +472,115 @@ impl CodePage {
 
     #[cfg(feature="load")]
     pub fn load() -> Result<&'static CodePage, CodePageLoadError> {
-        let mut loaded = LoadedCodePageGuard::acquire();
-        let loaded_code_page = loaded.code_page();
-        if let Some(code_page) = loaded_code_page {
-            return Ok(code_page);
+        match ActiveCodePage::load()? {
+            (_, ActiveCodePage::Sbcs(code_page)) => Ok(code_page),
+            (code_page, ActiveCodePage::Dbcs(_)) => Err(CodePageLoadError::CodePageIsDbcs { code_page }),
+        }
+    }
+
+    /// Same as [`CodePage::load`], but also tells which code page has been loaded.
+    #[cfg(feature="load")]
+    pub fn load_active() -> Result<LoadedCodePage, CodePageLoadError> {
+        let mut loaded = LOADED_CODE_PAGE.lock();
+        match loaded.get_or_load()? {
+            (number, ActiveCodePage::Sbcs(table)) => Ok(LoadedCodePage { number, system: loaded.system, table }),
+            (code_page, ActiveCodePage::Dbcs(_)) => Err(CodePageLoadError::CodePageIsDbcs { code_page }),
         }
-        let dos_ver = int_21h_ah_30h_dos_ver();
-        if dos_ver.al_major < 3 || dos_ver.al_major == 3 && dos_ver.ah_minor < 30 {
-            return Err(CodePageLoadError::Dos33Required);
+    }
+
+    /// Loads a code page by number, independently of the active one.
+    /// Tables read from files are kept in a small cache (up to 4 code pages) until [`CodePage::release_number`],
+    /// the built-in table is returned if there is no usable file.
+    #[cfg(feature="load")]
+    pub fn load_number(code_page: u16) -> Result<&'static CodePage, CodePageLoadError> {
+        if code_page == 0 {
+            return Err(CodePageLoadError::UnsupportedCodePage { code_page });
         }
-        let code_page_memory = int_31h_ax_0100h_rm_alloc(CODE_PAGE_SIZE.checked_add(15).unwrap() / 16)
-            .map_err(|e| CodePageLoadError::CanNotAlloc { err_code: e.ax_err })?;
-        let code_page_selector = RmAlloc { selector: code_page_memory.dx_selector };
-        let code_page_memory = unsafe { slice::from_raw_parts_mut(
-            ((code_page_memory.ax_segment as u32) << 4) as *mut u8,
-            CODE_PAGE_SIZE.into()
-        ) };
-        let code_page_n = int_21h_ax_6601h_code_page()
-            .map_err(|e| CodePageLoadError::CanNotGetSelectedCodePage { err_code: e.ax_err })?
-            .bx_active;
-        if !(100 ..= 999).contains(&code_page_n) {
-            return Err(CodePageLoadError::UnsupportedCodePage { code_page: code_page_n });
-        }
-        let mut code_page: [MaybeUninit<u8>; 13] = unsafe { MaybeUninit::uninit().assume_init() };
-        code_page[.. 9].copy_from_slice(unsafe { transmute::<&[u8], &[MaybeUninit<u8>]>(&b"CODEPAGE\\"[..]) });
-        code_page[9].write(b'0' + (code_page_n / 100) as u8);
-        code_page[10].write(b'0' + ((code_page_n % 100) / 10) as u8);
-        code_page[11].write(b'0' + (code_page_n % 10) as u8);
-        code_page[12].write(0);
-        let code_page: [u8; 13] = unsafe { transmute(code_page) };
-        let code_page = int_21h_ah_3Dh_open(code_page.as_ptr(), 0x00)
-            .map_err(|e| CodePageLoadError::CanNotOpenCodePageFile { code_page: code_page_n, err_code: e.ax_err })?
-            .ax_handle;
-        let code_page = File(code_page);
-        let mut code_page_buf: &mut [MaybeUninit<u8>] = unsafe { transmute(&mut code_page_memory[..]) };
-        loop {
-            if code_page_buf.is_empty() {
-                let mut byte: MaybeUninit<u8> = MaybeUninit::uninit();
-                let read = int_21h_ah_3Fh_read(code_page.0, slice::from_mut(&mut byte))
-                    .map_err(|e| CodePageLoadError::CanNotReadCodePageFile { code_page: code_page_n, err_code: e.ax_err })?
-                    .ax_read;
-                if read != 0 {
-                    return Err(CodePageLoadError::InvalidCodePageFile { code_page: code_page_n });
+        let mut cache = CODE_PAGE_CACHE.lock();
+        if let Some(cached) = cache.iter().flatten().find(|x| x.number == code_page) {
+            return Ok(cached.table);
+        }
+        let error = match cache.iter_mut().find(|x| x.is_none()) {
+            Some(slot) => {
+                let mut memory = None;
+                match load_code_page_file(code_page, &mut memory) {
+                    Ok(ActiveCodePage::Sbcs(table)) => {
+                        *slot = Some(CachedCodePage { number: code_page, table, memory });
+                        return Ok(table);
+                    },
+                    Ok(ActiveCodePage::Dbcs(_)) => return Err(CodePageLoadError::CodePageIsDbcs { code_page }),
+                    Err(error) => error,
                 }
-                break;
-            }
-            let read = int_21h_ah_3Fh_read(code_page.0, code_page_buf)
-                .map_err(|e| CodePageLoadError::CanNotReadCodePageFile { code_page: code_page_n, err_code: e.ax_err })?
-                .ax_read;
-            if read == 0 { break; }
-            code_page_buf = &mut code_page_buf[read as usize ..];
+            },
+            None => CodePageLoadError::CodePageCacheFull { code_page },
+        };
+        CodePage::builtin(code_page).ok_or(error)
+    }
+
+    /// Frees a code page loaded with [`CodePage::load_number`].
+    /// Returns `false` if there was nothing to free.
+    ///
+    /// # Safety
+    ///
+    /// References returned by `load_number(code_page)` must not be used after this call.
+    #[cfg(feature="load")]
+    pub unsafe fn release_number(code_page: u16) -> bool {
+        let mut cache = CODE_PAGE_CACHE.lock();
+        let Some(slot) = cache.iter_mut().find(|x| x.as_ref().is_some_and(|x| x.number == code_page)) else {
+            return false;
+        };
+        *slot = None;
+        true
+    }
+
+    /// Drops the cached table and loads the code page currently selected in DOS (or in `DOSCP`) again.
+    ///
+    /// # Safety
+    ///
+    /// The real-mode memory holding the previously loaded table is freed,
+    /// so references returned by earlier `load` calls must not be used after this call.
+    #[cfg(feature="load")]
+    pub unsafe fn reload() -> Result<&'static CodePage, CodePageLoadError> {
+        match ActiveCodePage::reload()? {
+            (_, ActiveCodePage::Sbcs(code_page)) => Ok(code_page),
+            (code_page, ActiveCodePage::Dbcs(_)) => Err(CodePageLoadError::CodePageIsDbcs { code_page }),
         }
-        if !code_page_buf.is_empty() {
-            return Err(CodePageLoadError::InvalidCodePageFile { code_page: code_page_n });
+    }
+
+    /// Loads the table for `code_page` into new memory and selects it in DOS (INT 21h AX=6602h).
+    /// On failure both DOS and the loaded table keep the previous code page.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`CodePage::reload`].
+    #[cfg(feature="load")]
+    pub unsafe fn set_active_code_page(code_page: u16) -> Result<&'static CodePage, CodePageLoadError> {
+        ActiveCodePage::set_sbcs(code_page)
+    }
+
+    /// Never fails: if the active code page cannot be loaded, returns [`CodePage::ascii`]
+    /// and reports the reason in the returned status.
+    ///
+    /// After a failure `print!`, `println!` and `inkey` use ASCII as well,
+    /// while [`CodePage::load`] and [`CodePage::load_active`] keep trying to load the code page and report errors.
+    #[cfg(feature="load")]
+    pub fn load_or_fallback() -> (&'static CodePage, CodePageLoadStatus) {
+        let mut loaded = LOADED_CODE_PAGE.lock();
+        match loaded.get_or_load() {
+            Ok((_, ActiveCodePage::Sbcs(code_page))) => {
+                let status = match loaded.builtin_error.clone() {
+                    Some(error) => CodePageLoadStatus::Builtin { error },
+                    None => CodePageLoadStatus::Loaded,
+                };
+                (code_page, status)
+            },
+            Ok((code_page, ActiveCodePage::Dbcs(_))) =>
+                (CodePage::ascii(), CodePageLoadStatus::Ascii { error: CodePageLoadError::CodePageIsDbcs { code_page } }),
+            Err(error) => {
+                loaded.ascii_fallback = true;
+                (CodePage::ascii(), CodePageLoadStatus::Ascii { error })
+            },
         }
-        let code_page = unsafe { &*(code_page_memory.as_ptr() as *const CodePage) };
-        forget(code_page_selector);
-        loaded_code_page.replace(code_page);
-        Ok(code_page)
     }
 
     #[cfg(feature="load")]
@@ -179,9 +600,408 @@ impl CodePage {
     }
 }
 
+pub const MAX_REPLACEMENT_LEN: usize = 16;
+
+#[cfg(not(feature="best-fit"))]
+fn best_fit(_c: char, _buf: &mut [u8], _encode_char: impl Fn(char, &mut [u8]) -> Option<usize>) -> Option<usize> {
+    None
+}
+
+fn encode_str_into(
+    s: &str,
+    buf: &mut [u8],
+    unmappable: Unmappable,
+    encode_char: impl Fn(char, &mut [u8]) -> Option<usize>
+) -> Result<Encoded, EncodeError> {
+    let mut written = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let mut replacement = [0; MAX_REPLACEMENT_LEN];
+        let c = compose_marks(c, &mut chars, &encode_char);
+        let replacement_len = if let Some(len) = encode_char(c, &mut replacement) {
+            len
+        } else {
+            match unmappable {
+                Unmappable::Strict => return Err(EncodeError { index, c, written }),
+                Unmappable::Replace(b) => {
+                    replacement[0] = b;
+                    1
+                },
+                Unmappable::Skip => 0,
+                Unmappable::Escape(style) => {
+                    let mut escape = [0; MAX_REPLACEMENT_LEN];
+                    let escape_len = style.escape(c, &mut escape);
+                    let mut len = 0;
+                    for &b in &escape[.. escape_len] {
+                        len += encode_char(b as char, &mut replacement[len ..]).unwrap_or_else(|| {
+                            replacement[len] = b'?';
+                            1
+                        });
+                    }
+                    len
+                },
+                Unmappable::BestFit(b) => best_fit(c, &mut replacement, &encode_char).unwrap_or_else(|| {
+                    replacement[0] = b;
+                    1
+                }),
+                Unmappable::Callback(f) => match f(c, &mut replacement) {
+                    Some(len) if len <= MAX_REPLACEMENT_LEN => len,
+                    _ => return Err(EncodeError { index, c, written }),
+                },
+            }
+        };
+        let replacement = &replacement[.. replacement_len];
+        let Some(tail) = buf.get_mut(written .. written + replacement.len()) else {
+            return Ok(Encoded { read: index, written });
+        };
+        tail.copy_from_slice(replacement);
+        written += replacement.len();
+    }
+    Ok(Encoded { read: s.len(), written })
+}
+
+#[cfg(feature="compose")]
+fn compose_marks(
+    mut c: char,
+    chars: &mut Peekable<CharIndices>,
+    encode_char: impl Fn(char, &mut [u8]) -> Option<usize>
+) -> char {
+    let can_encode = |c| encode_char(c, &mut [0; MAX_REPLACEMENT_LEN]).is_some();
+    while let Some(&(_, mark)) = chars.peek() {
+        if !compose::is_combining_mark(mark) { break; }
+        let Some(composed) = compose::compose(c, mark).filter(|&x| can_encode(x)) else { break; };
+        c = composed;
+        chars.next();
+    }
+    c
+}
+
+#[cfg(not(feature="compose"))]
+fn compose_marks(
+    c: char,
+    _chars: &mut Peekable<CharIndices>,
+    _encode_char: impl Fn(char, &mut [u8]) -> Option<usize>
+) -> char {
+    c
+}
+
+const fn str_char_at(s: &str, index: usize) -> char {
+    let s = s.as_bytes();
+    let b = s[index] as u32;
+    let c = if b >> 7 == 0 {
+        b
+    } else if b >> 5 == 0b110 {
+        ((b & 0x1F) << 6) | (s[index + 1] as u32 & 0x3F)
+    } else if b >> 4 == 0b1110 {
+        ((b & 0x0F) << 12) | ((s[index + 1] as u32 & 0x3F) << 6) | (s[index + 2] as u32 & 0x3F)
+    } else {
+        ((b & 0x07) << 18) | ((s[index + 1] as u32 & 0x3F) << 12) | ((s[index + 2] as u32 & 0x3F) << 6)
+            | (s[index + 3] as u32 & 0x3F)
+    };
+    unsafe { char::from_u32_unchecked(c) }
+}
+
+#[derive(Debug, Clone)]
+pub struct Coverage<'a> {
+    code_page: &'a CodePage,
+    s: &'a str,
+    index: usize,
+}
+
+impl<'a> Coverage<'a> {
+    pub const fn is_complete(&self) -> bool {
+        self.code_page.first_unmappable(self.s, self.index).is_none()
+    }
+
+    pub const fn unmappable_count(&self) -> usize {
+        let mut count = 0;
+        let mut index = self.index;
+        while let Some((i, c)) = self.code_page.first_unmappable(self.s, index) {
+            count += 1;
+            index = i + c.len_utf8();
+        }
+        count
+    }
+}
+
+impl<'a> Iterator for Coverage<'a> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, c) = self.code_page.first_unmappable(self.s, self.index)?;
+        self.index = index + c.len_utf8();
+        Some((index, c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.s.len() - self.index))
+    }
+}
+
+impl<'a> FusedIterator for Coverage<'a> { }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscapeStyle {
+    /// `\u{XXXX}`
+    Rust,
+    /// `&#NNNN;`
+    Html,
+}
+
+impl EscapeStyle {
+    fn escape(self, c: char, buf: &mut [u8]) -> usize {
+        let mut buf = SliceWriter { buf, len: 0 };
+        match self {
+            EscapeStyle::Rust => write!(buf, "\\u{{{:04X}}}", c as u32),
+            EscapeStyle::Html => write!(buf, "&#{};", c as u32),
+        }.unwrap();
+        buf.len
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Unmappable {
+    /// Fails on the first unmappable char.
+    ///
+    /// All policies first compose a char with the following combining marks if the code page has the result;
+    /// marks left over are handled by the policy as separate chars.
+    Strict,
+    Replace(u8),
+    Skip,
+    Escape(EscapeStyle),
+    /// Tries `CodePage::best_fit` first, then replaces with the given byte.
+    /// Without the `best-fit` feature works as [`Unmappable::Replace`].
+    BestFit(u8),
+    /// Writes up to [`MAX_REPLACEMENT_LEN`] already encoded bytes and returns their count,
+    /// or `None` to fail like [`Unmappable::Strict`].
+    /// A count greater than [`MAX_REPLACEMENT_LEN`] fails the same way as `None`.
+    Callback(fn(char, &mut [u8]) -> Option<usize>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Encoded {
+    pub read: usize,
+    pub written: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EncodeError {
+    pub index: usize,
+    pub c: char,
+    pub written: usize,
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "unmappable char {:?} at index {}", self.c, self.index)
+    }
+}
+
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    fn write_fmt(&mut self, args: fmt::Arguments) -> fmt::Result {
+        <Self as fmt::Write>::write_fmt(self, args)
+    }
+}
+
+impl<'a> fmt::Write for SliceWriter<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let tail = self.buf.get_mut(self.len .. self.len + s.len()).ok_or(fmt::Error)?;
+        tail.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodeError {
+    pub offset: usize,
+    pub byte: u8,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "undefined byte {:02X}h at offset {}", self.byte, self.offset)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Decode<'a, I> {
+    code_page: &'a CodePage,
+    bytes: I,
+    offset: usize,
+    display: bool,
+}
+
+impl<'a, I> Decode<'a, I> {
+    fn new(code_page: &'a CodePage, bytes: I) -> Self {
+        Decode { code_page, bytes, offset: 0, display: false }
+    }
+
+    pub fn display(self) -> Self {
+        Decode { display: true, ..self }
+    }
+
+    pub fn offset(&self) -> usize { self.offset }
+
+    pub fn into_inner(self) -> I { self.bytes }
+}
+
+impl<'a, I: Iterator<Item=u8>> Iterator for Decode<'a, I> {
+    type Item = Result<char, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let byte = self.bytes.next()?;
+        let offset = self.offset;
+        self.offset += 1;
+        let c = if self.display { self.code_page.to_display_char(byte) } else { self.code_page.to_char(byte) };
+        Some(c.ok_or(DecodeError { offset, byte }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bytes.size_hint()
+    }
+}
+
+impl<'a, I: ExactSizeIterator<Item=u8>> ExactSizeIterator for Decode<'a, I> { }
+
+impl<'a, I: FusedIterator<Item=u8>> FusedIterator for Decode<'a, I> { }
+
+pub trait DecodeExt: Iterator<Item=u8> + Sized {
+    fn oem_chars(self, code_page: &CodePage) -> Decode<'_, Self>;
+}
+
+impl<I: Iterator<Item=u8>> DecodeExt for I {
+    fn oem_chars(self, code_page: &CodePage) -> Decode<'_, Self> {
+        Decode::new(code_page, self)
+    }
+}
+
+#[cfg(feature="load")]
+#[derive(Clone, Copy)]
+pub(crate) enum ActiveCodePage {
+    Sbcs(&'static CodePage),
+    Dbcs(&'static DbcsCodePage),
+}
+
+#[cfg(feature="load")]
+impl ActiveCodePage {
+    pub(crate) fn load() -> Result<(u16, ActiveCodePage), CodePageLoadError> {
+        LOADED_CODE_PAGE.lock().get_or_load()
+    }
+
+    fn load_or_ascii_fallback() -> Result<ActiveCodePage, CodePageLoadError> {
+        let mut loaded = LOADED_CODE_PAGE.lock();
+        if loaded.code_page.is_none() && loaded.ascii_fallback {
+            return Ok(ActiveCodePage::Sbcs(CodePage::ascii()));
+        }
+        loaded.get_or_load().map(|(_, code_page)| code_page)
+    }
+
+    unsafe fn reload() -> Result<(u16, ActiveCodePage), CodePageLoadError> {
+        let mut loaded = LOADED_CODE_PAGE.lock();
+        loaded.code_page = None;
+        loaded.get_or_load()
+    }
+
+    unsafe fn set_sbcs(code_page_n: u16) -> Result<&'static CodePage, CodePageLoadError> {
+        let mut loaded = LOADED_CODE_PAGE.lock();
+        let (_, system_code_page_n) = dos_code_page()?;
+        let mut memory = None;
+        let (code_page, builtin_error) = match load_code_page(code_page_n, &mut memory)? {
+            (ActiveCodePage::Sbcs(code_page), builtin_error) => (code_page, builtin_error),
+            (ActiveCodePage::Dbcs(_), _) => return Err(CodePageLoadError::CodePageIsDbcs { code_page: code_page_n }),
+        };
+        if let Err(e) = int_21h_ax_6602h_set_code_page(code_page_n, system_code_page_n) {
+            return Err(CodePageLoadError::CanNotSetSelectedCodePage { code_page: code_page_n, err_code: e.ax_err });
+        }
+        loaded.replace(code_page_n, ActiveCodePage::Sbcs(code_page), memory, builtin_error);
+        loaded.system = Some(system_code_page_n);
+        Ok(code_page)
+    }
+
+    fn inkey(self) -> Result<Option<Either<u8, char>>, InkeyErr> {
+        match self {
+            ActiveCodePage::Sbcs(code_page) => code_page.inkey(),
+            ActiveCodePage::Dbcs(code_page) => code_page.inkey(),
+        }
+    }
+
+    fn encode_into(self, s: &str, buf: &mut [u8], unmappable: Unmappable) -> Result<Encoded, EncodeError> {
+        match self {
+            ActiveCodePage::Sbcs(code_page) => code_page.encode_into(s, buf, unmappable),
+            ActiveCodePage::Dbcs(code_page) => code_page.encode_into(s, buf, unmappable),
+        }
+    }
+}
+
+#[cfg(feature="load")]
+fn dos_code_page() -> Result<(u16, u16), CodePageLoadError> {
+    let dos_ver = int_21h_ah_30h_dos_ver();
+    if dos_ver.al_major < 3 || dos_ver.al_major == 3 && dos_ver.ah_minor < 30 {
+        return Err(CodePageLoadError::Dos33Required);
+    }
+    let code_page = int_21h_ax_6601h_code_page()
+        .map_err(|e| CodePageLoadError::CanNotGetSelectedCodePage { err_code: e.ax_err })?;
+    Ok((code_page.bx_active, code_page.dx_default))
+}
+
+#[cfg(feature="load")]
+fn selected_code_page() -> Result<(u16, Option<u16>), CodePageLoadError> {
+    match code_page_override()? {
+        Some(code_page_n) => Ok((code_page_n, dos_code_page().ok().map(|(_, system)| system))),
+        None => dos_code_page().map(|(code_page_n, system)| (code_page_n, Some(system))),
+    }
+}
+
+/// A file takes precedence over the built-in table, which is used only if the file cannot be loaded;
+/// the file error is returned along with it then.
+///
+/// `memory` receives a fresh real-mode block if the table is read from a file.
+#[cfg(feature="load")]
+fn load_code_page(
+    code_page_n: u16,
+    memory: &mut Option<RmAlloc>
+) -> Result<(ActiveCodePage, Option<CodePageLoadError>), CodePageLoadError> {
+    if code_page_n == 0 {
+        return Err(CodePageLoadError::UnsupportedCodePage { code_page: code_page_n });
+    }
+    match load_code_page_file(code_page_n, memory) {
+        Ok(code_page) => Ok((code_page, None)),
+        Err(error) => {
+            let Some(code_page) = CodePage::builtin(code_page_n) else { return Err(error); };
+            *memory = None;
+            Ok((ActiveCodePage::Sbcs(code_page), Some(error)))
+        },
+    }
+}
+
+#[cfg(feature="load")]
+fn load_code_page_file(code_page_n: u16, memory: &mut Option<RmAlloc>) -> Result<ActiveCodePage, CodePageLoadError> {
+    let tried = CodePageDirs::search_path();
+    let mut err_code = 0;
+    for dir in tried.iter() {
+        if let Ok(archive) = File::open(CodePageFile::Archive { dir }) {
+            // A corrupt or unreadable archive is skipped like a missing one.
+            if let Ok(Some(code_page)) = archive.read_archive_code_page(code_page_n, memory) {
+                return Ok(code_page);
+            }
+        }
+        match File::open(CodePageFile::Single { dir, code_page: code_page_n }) {
+            Ok(file) => return file.read_code_page(code_page_n, memory),
+            Err(e) => err_code = e,
+        }
+    }
+    Err(CodePageLoadError::CanNotOpenCodePageFile { code_page: code_page_n, err_code, tried })
+}
+
 #[cfg(feature="load")]
 pub fn inkey() -> Result<Option<Either<u8, char>>, InkeyErr> {
-    let cp = CodePage::load().map_err(|_| InkeyErr)?;
+    let cp = ActiveCodePage::load_or_ascii_fallback().map_err(|_| InkeyErr)?;
     cp.inkey()
 }
 
@@ -224,7 +1044,123 @@ impl fmt::Write for DosLastChanceWriter {
 }
 
 #[cfg(feature="load")]
-struct File(u16);
+struct File(u16, CodePageFile);
+
+#[cfg(feature="load")]
+impl File {
+    fn open(file: CodePageFile) -> Result<File, u16> {
+        let path = file.path().ok_or(DOS_ERR_PATH_NOT_FOUND as u16)?;
+        let handle = int_21h_ah_3Dh_open(path.as_ptr(), 0x00).map_err(|e| e.ax_err)?.ax_handle;
+        Ok(File(handle, file))
+    }
+
+    fn read_error(&self, code_page_n: u16, err_code: u16) -> CodePageLoadError {
+        CodePageLoadError::CanNotReadCodePageFile { code_page: code_page_n, file: self.1, err_code }
+    }
+
+    fn read(&self, code_page_n: u16, mut buf: &mut [MaybeUninit<u8>]) -> Result<usize, CodePageLoadError> {
+        let len = buf.len();
+        while !buf.is_empty() {
+            let chunk_len = buf.len().min(0x8000);
+            let read = int_21h_ah_3Fh_read(self.0, &mut buf[.. chunk_len])
+                .map_err(|e| self.read_error(code_page_n, e.ax_err))?
+                .ax_read;
+            if read == 0 { break; }
+            buf = &mut buf[read as usize ..];
+        }
+        Ok(len - buf.len())
+    }
+
+    fn read_bytes(&self, code_page_n: u16, buf: &mut [u8]) -> Result<usize, CodePageLoadError> {
+        self.read(code_page_n, unsafe { transmute::<&mut [u8], &mut [MaybeUninit<u8>]>(buf) })
+    }
+
+    fn read_archive_code_page(
+        &self,
+        code_page_n: u16,
+        memory: &mut Option<RmAlloc>
+    ) -> Result<Option<ActiveCodePage>, CodePageLoadError> {
+        let invalid = |error| CodePageLoadError::InvalidCodePageFile { code_page: code_page_n, file: self.1, error };
+        let mut header = [0; CODE_PAGE_ARCHIVE_HEADER_SIZE];
+        let header_len = self.read_bytes(code_page_n, &mut header)?;
+        let mut count = parse_archive_header(&header[.. header_len]).map_err(invalid)? as usize;
+        let mut entries = [0; 32 * CODE_PAGE_ARCHIVE_ENTRY_SIZE];
+        let offset = 'found: loop {
+            if count == 0 { return Ok(None); }
+            let entries = &mut entries[.. count.min(32) * CODE_PAGE_ARCHIVE_ENTRY_SIZE];
+            if self.read_bytes(code_page_n, entries)? != entries.len() {
+                return Err(invalid(FormatError::TooShort));
+            }
+            count -= entries.len() / CODE_PAGE_ARCHIVE_ENTRY_SIZE;
+            for entry in entries.chunks_exact(CODE_PAGE_ARCHIVE_ENTRY_SIZE) {
+                let (n, offset) = parse_archive_entry(entry.try_into().unwrap());
+                if n == code_page_n { break 'found offset; }
+            }
+        };
+        int_21h_ax_4200h_seek(self.0, offset).map_err(|e| self.read_error(code_page_n, e.ax_err))?;
+        self.read_code_page(code_page_n, memory).map(Some)
+    }
+
+    fn read_code_page(&self, code_page_n: u16, memory: &mut Option<RmAlloc>) -> Result<ActiveCodePage, CodePageLoadError> {
+        let invalid = |error| CodePageLoadError::InvalidCodePageFile { code_page: code_page_n, file: self.1, error };
+        let mut header = [0; CODE_PAGE_FILE_HEADER_SIZE];
+        let header_len = self.read_bytes(code_page_n, &mut header)?;
+        let header = FileHeader::parse(&header[.. header_len]).map_err(invalid)?;
+        if header.code_page != code_page_n {
+            return Err(CodePageLoadError::CodePageNumberMismatch {
+                code_page: code_page_n,
+                file: self.1,
+                file_code_page: header.code_page
+            });
+        }
+        let file_len = header.len as usize;
+        let memory_len = match header.kind {
+            CodePageKind::SingleByte => {
+                if file_len != PLAIN_CODE_PAGE_SIZE as usize && file_len != CODE_PAGE_SIZE as usize {
+                    return Err(invalid(FormatError::InvalidLength { len: header.len }));
+                }
+                CODE_PAGE_SIZE as usize
+            },
+            CodePageKind::DoubleByte => file_len,
+        };
+        let paragraphs = u16::try_from(memory_len.div_ceil(16))
+            .map_err(|_| invalid(FormatError::InvalidLength { len: header.len }))?;
+        let code_page_memory = int_31h_ax_0100h_rm_alloc(paragraphs)
+            .map_err(|e| CodePageLoadError::CanNotAlloc { err_code: e.ax_err })?;
+        let memory = memory.insert(RmAlloc {
+            selector: code_page_memory.dx_selector,
+            segment: code_page_memory.ax_segment,
+        });
+        let code_page_memory = unsafe { slice::from_raw_parts_mut(
+            ((memory.segment as u32) << 4) as *mut MaybeUninit<u8>,
+            memory_len
+        ) };
+        if self.read(code_page_n, &mut code_page_memory[.. file_len])? != file_len {
+            return Err(invalid(FormatError::TooShort));
+        }
+        let mut byte: MaybeUninit<u8> = MaybeUninit::uninit();
+        if !matches!(self.1, CodePageFile::Archive { .. }) && self.read(code_page_n, slice::from_mut(&mut byte))? != 0 {
+            return Err(invalid(FormatError::InvalidLength { len: header.len }));
+        }
+        for byte in &mut code_page_memory[file_len ..] {
+            byte.write(0);
+        }
+        let code_page_memory = unsafe { transmute::<&mut [MaybeUninit<u8>], &'static [u8]>(code_page_memory) };
+        header.check(&code_page_memory[.. file_len]).map_err(invalid)?;
+        let code_page = match header.kind {
+            CodePageKind::SingleByte => {
+                let code_page = unsafe { &*(code_page_memory.as_ptr() as *const CodePage) };
+                code_page.validate(file_len).map_err(invalid)?;
+                ActiveCodePage::Sbcs(code_page)
+            },
+            CodePageKind::DoubleByte => {
+                let code_page = DbcsCodePage::new(code_page_memory).ok_or(invalid(FormatError::InvalidTable))?;
+                ActiveCodePage::Dbcs(code_page)
+            },
+        };
+        Ok(code_page)
+    }
+}
 
 #[cfg(feature="load")]
 impl Drop for File {
@@ -238,46 +1174,107 @@ impl Drop for File {
 }
 
 #[cfg(feature="load")]
-struct LoadedCodePageGuard;
+static LOADED_CODE_PAGE: SpinLock<LoadedState> = SpinLock::new(LoadedState {
+    code_page: None,
+    system: None,
+    memory: None,
+    builtin_error: None,
+    ascii_fallback: false,
+});
 
 #[cfg(feature="load")]
-static LOADED_CODE_PAGE_GUARD: AtomicBool = AtomicBool::new(false);
-
-#[cfg(feature="load")]
-static mut LOADED_CODE_PAGE: Option<&'static CodePage> = None;
+struct LoadedState {
+    code_page: Option<(u16, ActiveCodePage)>,
+    system: Option<u16>,
+    memory: Option<RmAlloc>,
+    /// Why the built-in table is used instead of a file.
+    builtin_error: Option<CodePageLoadError>,
+    /// Set by `CodePage::load_or_fallback` on failure, lets console I/O use ASCII
+    /// until a code page is loaded.
+    ascii_fallback: bool,
+}
 
 #[cfg(feature="load")]
-impl LoadedCodePageGuard {
-    fn acquire() -> Self {
-        loop {
-            if LOADED_CODE_PAGE_GUARD.compare_exchange_weak(false, true, Ordering::SeqCst, Ordering::Relaxed).is_ok() {
-                break;
-            }
+impl LoadedState {
+    fn get_or_load(&mut self) -> Result<(u16, ActiveCodePage), CodePageLoadError> {
+        if let Some(code_page) = self.code_page {
+            return Ok(code_page);
         }
-        LoadedCodePageGuard
+        let (code_page_n, system) = selected_code_page()?;
+        self.system = system;
+        self.load(code_page_n)
+    }
+
+    fn load(&mut self, code_page_n: u16) -> Result<(u16, ActiveCodePage), CodePageLoadError> {
+        let mut memory = None;
+        let (code_page, builtin_error) = load_code_page(code_page_n, &mut memory)?;
+        self.replace(code_page_n, code_page, memory, builtin_error);
+        Ok((code_page_n, code_page))
     }
 
-    fn code_page(&mut self) -> &mut Option<&'static CodePage> {
-        unsafe { &mut *ptr::addr_of_mut!(LOADED_CODE_PAGE) }
+    // Frees the memory of the previous table.
+    fn replace(
+        &mut self,
+        code_page_n: u16,
+        code_page: ActiveCodePage,
+        memory: Option<RmAlloc>,
+        builtin_error: Option<CodePageLoadError>
+    ) {
+        self.code_page = Some((code_page_n, code_page));
+        self.memory = memory;
+        self.builtin_error = builtin_error;
     }
 }
 
 #[cfg(feature="load")]
-impl Drop for LoadedCodePageGuard {
-    fn drop(&mut self) {
-        LOADED_CODE_PAGE_GUARD.store(false, Ordering::SeqCst);
-    }
+const CODE_PAGE_CACHE_SIZE: usize = 4;
+
+#[cfg(feature="load")]
+struct CachedCodePage {
+    number: u16,
+    table: &'static CodePage,
+    #[allow(dead_code)]
+    memory: Option<RmAlloc>,
+}
+
+#[cfg(feature="load")]
+static CODE_PAGE_CACHE: SpinLock<[Option<CachedCodePage>; CODE_PAGE_CACHE_SIZE]> =
+    SpinLock::new([const { None }; CODE_PAGE_CACHE_SIZE]);
+
+#[cfg(feature="load")]
+#[derive(Debug, Clone, Copy)]
+pub struct LoadedCodePage {
+    pub number: u16,
+    /// The system (boot) code page reported by DOS, `None` if DOS could not be asked.
+    pub system: Option<u16>,
+    pub table: &'static CodePage,
+}
+
+#[cfg(feature="load")]
+#[derive(Debug)]
+pub enum CodePageLoadStatus {
+    Loaded,
+    /// The code page file could not be loaded, the built-in table is used.
+    Builtin { error: CodePageLoadError },
+    Ascii { error: CodePageLoadError },
 }
 
 #[cfg(feature="load")]
+#[derive(Clone)]
 pub enum CodePageLoadError {
     Dos33Required,
     CanNotAlloc { err_code: u16 },
     CanNotGetSelectedCodePage { err_code: u16 },
+    CanNotSetSelectedCodePage { code_page: u16, err_code: u16 },
     UnsupportedCodePage { code_page: u16 },
-    CanNotOpenCodePageFile { code_page: u16, err_code: u16 },
-    CanNotReadCodePageFile { code_page: u16, err_code: u16 },
-    InvalidCodePageFile { code_page: u16 },
+    InvalidCodePageOverride { value: &'static [u8] },
+    CanNotOpenCodePageFile { code_page: u16, err_code: u16, tried: CodePageDirs },
+    CanNotReadCodePageFile { code_page: u16, file: CodePageFile, err_code: u16 },
+    InvalidCodePageFile { code_page: u16, file: CodePageFile, error: FormatError },
+    CodePageNumberMismatch { code_page: u16, file: CodePageFile, file_code_page: u16 },
+    CodePageIsDbcs { code_page: u16 },
+    CodePageIsNotDbcs { code_page: u16 },
+    CodePageCacheFull { code_page: u16 },
 }
 
 #[cfg(feature="load")]
@@ -287,10 +1284,16 @@ impl CodePageLoadError {
             CodePageLoadError::Dos33Required => None,
             CodePageLoadError::CanNotAlloc { .. } => None,
             CodePageLoadError::CanNotGetSelectedCodePage { .. } => None,
+            CodePageLoadError::InvalidCodePageOverride { .. } => None,
+            &CodePageLoadError::CanNotSetSelectedCodePage { code_page, .. } => Some(code_page),
             &CodePageLoadError::UnsupportedCodePage { code_page } => Some(code_page),
             &CodePageLoadError::CanNotOpenCodePageFile { code_page, .. } => Some(code_page),
             &CodePageLoadError::CanNotReadCodePageFile { code_page, .. } => Some(code_page),
-            &CodePageLoadError::InvalidCodePageFile { code_page } => Some(code_page),
+            &CodePageLoadError::InvalidCodePageFile { code_page, .. } => Some(code_page),
+            &CodePageLoadError::CodePageNumberMismatch { code_page, .. } => Some(code_page),
+            &CodePageLoadError::CodePageIsDbcs { code_page } => Some(code_page),
+            &CodePageLoadError::CodePageIsNotDbcs { code_page } => Some(code_page),
+            &CodePageLoadError::CodePageCacheFull { code_page } => Some(code_page),
         }
     }
 }
@@ -304,12 +1307,31 @@ impl Display for CodePageLoadError {
                 write!(f, "cannot allocate real-mode memory for code page ({err_code:04X}h)"),
             CodePageLoadError::CanNotGetSelectedCodePage { err_code } =>
                 write!(f, "cannon get selected code page ({err_code:04X}h)"),
+            CodePageLoadError::CanNotSetSelectedCodePage { code_page, err_code } =>
+                write!(f, "cannot select code page {code_page} ({err_code:04X}h)"),
             CodePageLoadError::UnsupportedCodePage { code_page } => write!(f, "unsupported code page {code_page}"),
-            CodePageLoadError::CanNotOpenCodePageFile { code_page, err_code } =>
-                write!(f, "cannot open code page file 'CODEPAGE\\{code_page}' ({err_code:04X}h)"),
-            CodePageLoadError::CanNotReadCodePageFile { code_page, err_code } =>
-                write!(f, "cannot read code page file 'CODEPAGE\\{code_page}' ({err_code:04X}h)"),
-            CodePageLoadError::InvalidCodePageFile { code_page } => write!(f, "invalid code page file 'CODEPAGE\\{code_page}'"),
+            CodePageLoadError::InvalidCodePageOverride { value } => {
+                write!(f, "invalid code page '")?;
+                write_path(f, value)?;
+                write!(f, "' in DOSCP")
+            },
+            CodePageLoadError::CanNotOpenCodePageFile { code_page, err_code, tried } =>
+                write!(f, "cannot open code page {code_page} file ({err_code:04X}h), tried {tried}"),
+            CodePageLoadError::CanNotReadCodePageFile { file, err_code, .. } =>
+                write!(f, "cannot read code page file '{file}' ({err_code:04X}h)"),
+            CodePageLoadError::InvalidCodePageFile { code_page, file: file @ CodePageFile::Archive { .. }, error } =>
+                write!(f, "invalid code page {code_page} in '{file}': {error}"),
+            CodePageLoadError::InvalidCodePageFile { file, error, .. } =>
+                write!(f, "invalid code page file '{file}': {error}"),
+            CodePageLoadError::CodePageNumberMismatch { code_page, file: file @ CodePageFile::Archive { .. }, file_code_page } =>
+                write!(f, "code page {code_page} entry in '{file}' contains code page {file_code_page}"),
+            CodePageLoadError::CodePageNumberMismatch { file, file_code_page, .. } =>
+                write!(f, "code page file '{file}' contains code page {file_code_page}"),
+            CodePageLoadError::CodePageIsDbcs { code_page } => write!(f, "code page {code_page} is a double-byte code page"),
+            CodePageLoadError::CodePageIsNotDbcs { code_page } =>
+                write!(f, "code page {code_page} is not a double-byte code page"),
+            CodePageLoadError::CodePageCacheFull { code_page } =>
+                write!(f, "cannot load code page {code_page}: too many code pages loaded"),
         }
     }
 }
@@ -324,6 +1346,7 @@ impl Debug for CodePageLoadError {
 #[cfg(feature="load")]
 struct RmAlloc {
     selector: u16,
+    segment: u16,
 }
 
 #[cfg(feature="load")]
@@ -334,72 +1357,94 @@ impl Drop for RmAlloc {
 }
 
 #[cfg(feature="load")]
-pub struct DosStdout { pub panic: bool }
+static STDOUT_UNMAPPABLE: SpinLock<Unmappable> = SpinLock::new(Unmappable::BestFit(b'?'));
+
+/// Created with [`DosStdout::new`].
+#[cfg(feature="load")]
+pub struct DosStdout { pub panic: bool, unmappable: Unmappable }
 
 #[cfg(feature="load")]
 impl DosStdout {
+    pub fn new(panic: bool) -> Self {
+        DosStdout { panic, unmappable: Self::default_unmappable() }
+    }
+
+    pub fn unmappable(&self) -> Unmappable {
+        self.unmappable
+    }
+
+    pub fn set_unmappable(&mut self, unmappable: Unmappable) {
+        self.unmappable = unmappable;
+    }
+
+    pub fn default_unmappable() -> Unmappable {
+        *STDOUT_UNMAPPABLE.lock()
+    }
+
+    pub fn set_default_unmappable(unmappable: Unmappable) {
+        *STDOUT_UNMAPPABLE.lock() = unmappable;
+    }
+
     pub fn write_fmt(&mut self, args: fmt::Arguments) -> fmt::Result {
         <Self as fmt::Write>::write_fmt(self, args)
     }
 }
 
+#[cfg(feature="load")]
+fn dos_stdout_write(buf: &[u8]) -> fmt::Result {
+    if buf.is_empty() { return Ok(()); }
+    match int_21h_ah_40h_write(1, buf) {
+        Err(_) => Err(fmt::Error),
+        Ok(AxWritten { ax_written }) if usize::from(ax_written) < buf.len() => Err(fmt::Error),
+        _ => Ok(()),
+    }
+}
+
 #[cfg(feature="load")]
 impl fmt::Write for DosStdout {
     fn write_char(&mut self, c: char) -> fmt::Result {
-        let cp = CodePage::load();
-        let cp = if self.panic { cp.unwrap() } else { cp.map_err(|_| fmt::Error)? };
-        let c = cp.from_char(c).unwrap_or(b'?');
-        match c {
-            b'\r' => Ok(()),
-            b'\n' => match int_21h_ah_40h_write(1, b"\r\n") {
-                Err(_) => Err(fmt::Error),
-                Ok(AxWritten { ax_written }) if ax_written < 2 => Err(fmt::Error),
-                _ => Ok(()),
-            },
-            c => match int_21h_ah_40h_write(1, &[c]) {
-                Err(_) | Ok(AxWritten { ax_written: 0 }) => Err(fmt::Error),
-                _ => Ok(()),
-            }
-        }
+        if c == '\r' { return Ok(()); }
+        self.write_str(c.encode_utf8(&mut [0; 4]))
     }
 
     fn write_str(&mut self, s: &str) -> fmt::Result {
-        let cp = CodePage::load();
+        let cp = ActiveCodePage::load_or_ascii_fallback();
         let cp = if self.panic { cp.unwrap() } else { cp.map_err(|_| fmt::Error)? };
         let mut buf = [0; 128];
-        for (skip_newline, s) in s.split('\n').identify_last() {
-            let mut i = 0;
-            for (is_last, c) in s.chars().identify_last() {
-                buf[i] = cp.from_char(c).unwrap_or(b'?');
-                i += 1;
-                if is_last || i == buf.len() {
-                    match int_21h_ah_40h_write(1, &buf[.. i]) {
-                        Err(_) => return Err(fmt::Error),
-                        Ok(AxWritten { ax_written }) if usize::from(ax_written) < i => return Err(fmt::Error),
-                        _ => { },
-                    }
-                    i = 0;
-                }
+        for (skip_newline, mut s) in s.split('\n').identify_last() {
+            while !s.is_empty() {
+                let (read, written, res) = match cp.encode_into(s, &mut buf, self.unmappable) {
+                    Ok(Encoded { read, written }) => (read, written, Ok(())),
+                    Err(e) => (e.index, e.written, Err(fmt::Error)),
+                };
+                dos_stdout_write(&buf[.. written])?;
+                res?;
+                s = &s[read ..];
             }
             if !skip_newline {
-                match int_21h_ah_40h_write(1, b"\r\n") {
-                    Err(_) => return Err(fmt::Error),
-                    Ok(AxWritten { ax_written }) if ax_written < 2 => return Err(fmt::Error),
-                    _ => { },
-                }
+                dos_stdout_write(b"\r\n")?;
             }
         }
         Ok(())
     }
 }
 
+#[macro_export]
+macro_rules! include_code_page {
+    (
+        $code_page:expr
+    ) => {
+        const { $crate::tables::code_page($code_page) }
+    };
+}
+
 #[cfg(feature="load")]
 #[macro_export]
 macro_rules! print {
     (
         $($arg:tt)*
     ) => {
-        $crate::std_write!($crate::DosStdout { panic: true }, $($arg)*).unwrap()
+        $crate::std_write!($crate::DosStdout::new(true), $($arg)*).unwrap()
     };
 }
 
@@ -408,11 +1453,11 @@ macro_rules! print {
 macro_rules! println {
     (
     ) => {
-        $crate::std_writeln!($crate::DosStdout { panic: true }).unwrap()
+        $crate::std_writeln!($crate::DosStdout::new(true)).unwrap()
     };
     (
         $($arg:tt)*
     ) => {
-        $crate::std_writeln!($crate::DosStdout { panic: true }, $($arg)*).unwrap()
+        $crate::std_writeln!($crate::DosStdout::new(true), $($arg)*).unwrap()
     };
 }
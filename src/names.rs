@@ -0,0 +1,64 @@
+const NAMED_CODE_PAGES: &[(&str, u16)] = &[
+    ("macintosh", 10000),
+    ("mac-roman", 10000),
+    ("x-mac-cyrillic", 10007),
+    ("mac-cyrillic", 10007),
+    ("koi8-r", 20866),
+    ("koi8-u", 21866),
+    ("iso-8859-1", 28591),
+    ("latin1", 28591),
+    ("iso-8859-2", 28592),
+    ("latin2", 28592),
+    ("iso-8859-3", 28593),
+    ("latin3", 28593),
+    ("iso-8859-4", 28594),
+    ("latin4", 28594),
+    ("iso-8859-5", 28595),
+    ("cyrillic", 28595),
+    ("iso-8859-6", 28596),
+    ("arabic", 28596),
+    ("iso-8859-7", 28597),
+    ("greek", 28597),
+    ("iso-8859-8", 28598),
+    ("hebrew", 28598),
+    ("iso-8859-9", 28599),
+    ("latin5", 28599),
+    ("iso-8859-10", 28600),
+    ("latin6", 28600),
+    ("iso-8859-11", 28601),
+    ("iso-8859-13", 28603),
+    ("latin7", 28603),
+    ("iso-8859-14", 28604),
+    ("latin8", 28604),
+    ("iso-8859-15", 28605),
+    ("latin9", 28605),
+    ("iso-8859-16", 28606),
+    ("latin10", 28606),
+];
+
+const NUMBERED_PREFIXES: &[&str] = &["cp", "ibm", "windows", "x-cp"];
+
+fn normalized(s: &str) -> impl Iterator<Item=u8> + '_ {
+    s.bytes().filter(|&b| !matches!(b, b'-' | b'_' | b' ' | b'.')).map(|b| b.to_ascii_lowercase())
+}
+
+fn parse_number(s: &str) -> Option<u16> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) { return None; }
+    s.parse().ok().filter(|&n| n != 0)
+}
+
+pub fn code_page_by_name(name: &str) -> Option<u16> {
+    let name = name.trim();
+    if let Some(n) = parse_number(name) { return Some(n); }
+    if let Some(&(_, n)) = NAMED_CODE_PAGES.iter().find(|&&(x, _)| normalized(x).eq(normalized(name))) {
+        return Some(n);
+    }
+    NUMBERED_PREFIXES.iter().find_map(|prefix| {
+        if !name.get(.. prefix.len())?.eq_ignore_ascii_case(prefix) { return None; }
+        parse_number(name[prefix.len() ..].trim_start_matches(['-', '_', ' ']))
+    })
+}
+
+pub fn code_page_name(code_page: u16) -> Option<&'static str> {
+    NAMED_CODE_PAGES.iter().find(|&&(_, n)| n == code_page).map(|&(name, _)| name)
+}
@@ -0,0 +1,155 @@
+use crate::{CodePageLoadError, code_page_by_name};
+use crate::env::{env_var, program_dir};
+use core::fmt::{self, Display, Formatter};
+use core::str::{self};
+
+const MAX_PATH: usize = 128;
+
+pub(crate) fn write_path(f: &mut Formatter, path: &[u8]) -> fmt::Result {
+    for &b in path {
+        write!(f, "{}", if b >> 7 == 0 { b as char } else { '?' })?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodePageDir {
+    PathVar(&'static [u8]),
+    ProgramDir(&'static [u8]),
+    CurrentDir,
+}
+
+impl CodePageDir {
+    pub fn path(&self) -> &'static [u8] {
+        match self {
+            CodePageDir::PathVar(path) => path,
+            CodePageDir::ProgramDir(path) => path,
+            CodePageDir::CurrentDir => b"",
+        }
+    }
+}
+
+impl Display for CodePageDir {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CodePageDir::PathVar(path) => {
+                write_path(f, path)?;
+                write!(f, " (DOSCP_PATH)")
+            },
+            CodePageDir::ProgramDir(path) => {
+                write_path(f, path)?;
+                write!(f, " (program directory)")
+            },
+            CodePageDir::CurrentDir => write!(f, "current directory"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodePageDirs([Option<CodePageDir>; 3]);
+
+impl CodePageDirs {
+    pub(crate) fn search_path() -> CodePageDirs {
+        CodePageDirs([
+            env_var(b"DOSCP_PATH").filter(|x| !x.is_empty()).map(CodePageDir::PathVar),
+            program_dir().map(CodePageDir::ProgramDir),
+            Some(CodePageDir::CurrentDir),
+        ])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=CodePageDir> + '_ {
+        self.0.iter().filter_map(|&dir| dir)
+    }
+}
+
+impl Display for CodePageDirs {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, dir) in self.iter().enumerate() {
+            if i != 0 { write!(f, ", ")?; }
+            write!(f, "{dir}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodePageFile {
+    Single { dir: CodePageDir, code_page: u16 },
+    Archive { dir: CodePageDir },
+}
+
+impl CodePageFile {
+    pub fn dir(&self) -> CodePageDir {
+        match self {
+            CodePageFile::Single { dir, .. } => *dir,
+            CodePageFile::Archive { dir } => *dir,
+        }
+    }
+
+    pub(crate) fn path(&self) -> Option<PathZ> {
+        let mut path = PathZ::new(self.dir().path())?;
+        match self {
+            CodePageFile::Single { code_page, .. } => {
+                path.push(b"CODEPAGE\\")?;
+                path.push_number(*code_page)?;
+            },
+            CodePageFile::Archive { .. } => path.push(b"CODEPAGE.DAT")?,
+        }
+        Some(path)
+    }
+}
+
+impl Display for CodePageFile {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let dir = self.dir().path();
+        write_path(f, dir)?;
+        if !dir.is_empty() && !dir.ends_with(b"\\") { write!(f, "\\")?; }
+        match self {
+            CodePageFile::Single { code_page, .. } => write!(f, "CODEPAGE\\{code_page}"),
+            CodePageFile::Archive { .. } => write!(f, "CODEPAGE.DAT"),
+        }
+    }
+}
+
+pub(crate) struct PathZ {
+    buf: [u8; MAX_PATH],
+    len: usize,
+}
+
+impl PathZ {
+    fn new(dir: &[u8]) -> Option<PathZ> {
+        let mut path = PathZ { buf: [0; MAX_PATH], len: 0 };
+        path.push(dir)?;
+        if !dir.is_empty() && !dir.ends_with(b"\\") { path.push(b"\\")?; }
+        Some(path)
+    }
+
+    fn push(&mut self, bytes: &[u8]) -> Option<()> {
+        if bytes.contains(&0) { return None; }
+        let buf = self.buf.get_mut(self.len .. self.len + bytes.len()).filter(|_| self.len + bytes.len() < MAX_PATH)?;
+        buf.copy_from_slice(bytes);
+        self.len += bytes.len();
+        Some(())
+    }
+
+    fn push_number(&mut self, n: u16) -> Option<()> {
+        let mut digits = [0; 5];
+        let len = n.checked_ilog10().unwrap_or(0) as usize + 1;
+        let mut n = n;
+        for digit in digits[.. len].iter_mut().rev() {
+            *digit = b'0' + (n % 10) as u8;
+            n /= 10;
+        }
+        self.push(&digits[.. len])
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const u8 {
+        self.buf.as_ptr()
+    }
+}
+
+pub(crate) fn code_page_override() -> Result<Option<u16>, CodePageLoadError> {
+    let Some(value) = env_var(b"DOSCP") else { return Ok(None); };
+    let code_page = str::from_utf8(value).ok().and_then(code_page_by_name).filter(|&n| n != 0);
+    code_page.map(Some).ok_or(CodePageLoadError::InvalidCodePageOverride { value })
+}
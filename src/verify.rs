@@ -0,0 +1,165 @@
+use crate::{BUCKETS, CodePage, HASH_PARAM, HASH_PARAM_ASCII_LOW_HALF, OVERFLOW, OVERFLOW_SIZE};
+use crate::{PLAIN_CODE_PAGE_SIZE, hash};
+use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Inconsistency {
+    LowHalfWithAsciiFlag { hash_param: u16 },
+    SurrogateChar { byte: u8 },
+    DuplicateMapping { byte: u8, other: u8 },
+    MissingBucketEntry { byte: u8 },
+    DanglingBucketEntry { offset: usize },
+}
+
+impl Display for Inconsistency {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Inconsistency::LowHalfWithAsciiFlag { hash_param } =>
+                write!(f, "hash param {hash_param:04X}h marks low half as ASCII, but the low half table is not empty"),
+            Inconsistency::SurrogateChar { byte } => write!(f, "byte {byte:02X}h is mapped to a surrogate code point"),
+            Inconsistency::DuplicateMapping { byte, other } =>
+                write!(f, "bytes {other:02X}h and {byte:02X}h are mapped to the same char"),
+            Inconsistency::MissingBucketEntry { byte } => write!(f, "byte {byte:02X}h is missing in its hash bucket"),
+            Inconsistency::DanglingBucketEntry { offset } => write!(f, "dangling hash bucket entry at offset {offset}"),
+        }
+    }
+}
+
+const CHARS_START: usize = 1;
+const BUCKET_ENTRIES_START: usize = CHARS_START + 256;
+const BUCKET_SLOTS_START: usize = BUCKET_ENTRIES_START + 128;
+const OVERFLOW_SLOTS_START: usize = BUCKET_SLOTS_START + HASH_PARAM - BUCKETS;
+const END: usize = OVERFLOW_SLOTS_START + OVERFLOW_SIZE;
+
+impl CodePage {
+    pub fn verify(&self) -> Inconsistencies<'_> {
+        Inconsistencies { code_page: self, step: 0 }
+    }
+
+    fn raw_char(&self, byte: u8) -> u32 {
+        let offset = if byte >> 7 != 0 {
+            2 * (byte & 0x7F) as usize
+        } else if byte == 0 || self.has_ascii_low_half() {
+            return byte as u32;
+        } else {
+            PLAIN_CODE_PAGE_SIZE as usize + 2 * byte as usize
+        };
+        u16::from_be_bytes([self.0[offset], self.0[offset + 1]]) as u32
+    }
+
+    fn check_hash_param(&self) -> Option<Inconsistency> {
+        let hash_param = self.hash_param();
+        if hash_param & HASH_PARAM_ASCII_LOW_HALF != 0 && self.0[PLAIN_CODE_PAGE_SIZE as usize ..].iter().any(|&x| x != 0) {
+            return Some(Inconsistency::LowHalfWithAsciiFlag { hash_param });
+        }
+        None
+    }
+
+    fn check_char(&self, byte: u8) -> Option<Inconsistency> {
+        let c = self.raw_char(byte);
+        if c == 0 && byte != 0 { return None; }
+        if (0xD800 .. 0xE000).contains(&c) { return Some(Inconsistency::SurrogateChar { byte }); }
+        let other = (0 .. byte).find(|&other| self.raw_char(other) == c)?;
+        Some(Inconsistency::DuplicateMapping { byte, other })
+    }
+
+    fn check_bucket_entry(&self, i: u8) -> Option<Inconsistency> {
+        let w = self.raw_char(0x80 | i);
+        if w == 0 || (0xD800 .. 0xE000).contains(&w) { return None; }
+        let w = w as u16;
+        if let Some(slots) = self.bucket_slots(w) {
+            if slots.contains(&i) { return None; }
+            if !self.is_full_bucket(w) { return Some(Inconsistency::MissingBucketEntry { byte: 0x80 | i }); }
+        }
+        if self.0[OVERFLOW .. OVERFLOW + OVERFLOW_SIZE].contains(&i) { return None; }
+        Some(Inconsistency::MissingBucketEntry { byte: 0x80 | i })
+    }
+
+    fn slots(&self, offset: usize) -> [u8; 2] {
+        [self.0[offset], self.0[offset + 1]]
+    }
+
+    fn bucket_slots(&self, w: u16) -> Option<[u8; 2]> {
+        let bucket = hash(w, self.hash_param()) as usize;
+        if bucket == 0x7F { return None; }
+        Some(self.slots(BUCKETS + 2 * bucket))
+    }
+
+    fn is_full_bucket(&self, w: u16) -> bool {
+        let bucket = hash(w, self.hash_param()) as usize;
+        self.bucket_slots(w).is_none_or(|slots| slots.iter().all(|&slot| self.is_valid_bucket_slot(bucket, slot)))
+    }
+
+    fn slot_char(&self, slot: u8) -> Option<u16> {
+        if slot >> 7 != 0 { return None; }
+        let w = self.raw_char(0x80 | slot);
+        if w == 0 || w >> 16 != 0 { return None; }
+        Some(w as u16)
+    }
+
+    fn is_valid_bucket_slot(&self, bucket: usize, slot: u8) -> bool {
+        self.slot_char(slot).is_some_and(|w| hash(w, self.hash_param()) as usize == bucket)
+    }
+
+    fn is_valid_overflow_slot(&self, slot: u8) -> bool {
+        self.slot_char(slot).is_some_and(|w|
+            self.is_full_bucket(w) && self.bucket_slots(w).is_none_or(|slots| !slots.contains(&slot))
+        )
+    }
+
+    fn check_bucket_slot(&self, offset: usize) -> Option<Inconsistency> {
+        let slot = self.0[offset];
+        if slot == 128 { return None; }
+        let first_slot = offset & 1 == 0;
+        if !first_slot && (self.0[offset - 1] == 128 || self.0[offset - 1] == slot) || !self.is_valid_bucket_slot((offset - BUCKETS) / 2, slot) {
+            return Some(Inconsistency::DanglingBucketEntry { offset });
+        }
+        None
+    }
+
+    // The overflow area is ordered by char, with unused slots at the end.
+    fn check_overflow_slot(&self, offset: usize) -> Option<Inconsistency> {
+        let slot = self.0[offset];
+        if slot == 128 { return None; }
+        let ordered = offset == OVERFLOW || self.slot_char(self.0[offset - 1]).is_some_and(|prev|
+            self.slot_char(slot).is_some_and(|w| prev < w)
+        );
+        if !ordered || !self.is_valid_overflow_slot(slot) {
+            return Some(Inconsistency::DanglingBucketEntry { offset });
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Inconsistencies<'a> {
+    code_page: &'a CodePage,
+    step: usize,
+}
+
+impl<'a> Iterator for Inconsistencies<'a> {
+    type Item = Inconsistency;
+
+    fn next(&mut self) -> Option<Inconsistency> {
+        while self.step < END {
+            let step = self.step;
+            self.step += 1;
+            let inconsistency = if step < CHARS_START {
+                self.code_page.check_hash_param()
+            } else if step < BUCKET_ENTRIES_START {
+                self.code_page.check_char((step - CHARS_START) as u8)
+            } else if step < BUCKET_SLOTS_START {
+                self.code_page.check_bucket_entry((step - BUCKET_ENTRIES_START) as u8)
+            } else if step < OVERFLOW_SLOTS_START {
+                self.code_page.check_bucket_slot(BUCKETS + step - BUCKET_SLOTS_START)
+            } else {
+                self.code_page.check_overflow_slot(OVERFLOW + step - OVERFLOW_SLOTS_START)
+            };
+            if inconsistency.is_some() { return inconsistency; }
+        }
+        None
+    }
+}
+
+impl<'a> FusedIterator for Inconsistencies<'a> { }